@@ -0,0 +1,346 @@
+use serde_derive::{Serialize, Deserialize};
+
+use brdgme_game::Log;
+use brdgme_markup::Node as N;
+
+use crate::corp::Corp;
+use crate::errors::{AcquireError, Action};
+use crate::i18n::{Locale, Msg};
+use crate::options::AuctionStyle;
+use crate::render::comma_list;
+use crate::{Game, Phase, PubState, BONUS_ROUNDING};
+
+/// The smallest amount a bid can be raised by.
+pub const BID_STEP: usize = BONUS_ROUNDING;
+
+/// A player's sealed bid.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Bid {
+    Pending,
+    /// A bid which has been made but isn't shown to the viewer.
+    Hidden,
+    Amount(usize),
+    Pass,
+}
+
+fn amount(a: usize) -> N {
+    N::Bold(vec![N::text(format!("${}", a))])
+}
+
+impl Phase {
+    /// Players who can act now, which is everyone yet to bid in a sealed auction.
+    pub fn acting(&self) -> Vec<usize> {
+        match *self {
+            Phase::SealedBid { ref bids, .. } => (0..bids.len())
+                .filter(|p| bids[*p] == Bid::Pending)
+                .collect(),
+            _ => vec![self.whose_turn()],
+        }
+    }
+}
+
+impl Game {
+    /// Put the first shares of a newly founded corporation up for auction when using the
+    /// auction variant, otherwise move straight on to buying.
+    pub(crate) fn start_auction(&mut self, player: usize, corp: Corp) -> Vec<Log> {
+        let n = self.auction_shares(&corp);
+        let style = match self.options.auction {
+            Some(rule) if n > 0 => rule.style,
+            _ => {
+                self.buy_phase(player);
+                return vec![];
+            }
+        };
+        self.phase = match style {
+            AuctionStyle::Sealed => Phase::SealedBid {
+                player,
+                corp,
+                bids: vec![Bid::Pending; self.players.len()],
+            },
+            AuctionStyle::Open => Phase::OpenBid {
+                player,
+                corp,
+                bidder: player,
+                high: None,
+                passed: vec![],
+            },
+        };
+        self.public_logs(|l| {
+            l.fill(
+                Msg::AuctionStarted,
+                vec![
+                    N::Bold(vec![N::text(format!("{}", n))]),
+                    self.render_corp(&corp),
+                ],
+            )
+        })
+    }
+
+    fn auction_shares(&self, corp: &Corp) -> usize {
+        self.options
+            .auction
+            .map(|rule| rule.shares)
+            .unwrap_or(0)
+            .min(self.shares.get(corp).cloned().unwrap_or(0))
+    }
+
+    /// The lowest bid which can be made in the current auction.
+    pub fn min_bid(&self) -> Option<usize> {
+        match self.phase {
+            Phase::SealedBid { .. } => Some(BID_STEP),
+            Phase::OpenBid { high, .. } => {
+                Some(high.map(|(_, a)| a + BID_STEP).unwrap_or(BID_STEP))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn handle_bid_command(
+        &mut self,
+        player: usize,
+        bid: usize,
+    ) -> Result<(Vec<Log>, bool), AcquireError> {
+        self.ensure_not_finished()?;
+        self.ensure_player_turn(player)?;
+        let min = self.min_bid().ok_or(AcquireError::WrongPhase(Action::Bid))?;
+        if bid < min {
+            return Err(AcquireError::BidTooLow { min });
+        }
+        let cash = self.players[player].money;
+        if bid > cash {
+            return Err(AcquireError::InsufficientFunds { cost: bid, cash });
+        }
+        match self.phase {
+            Phase::SealedBid { ref mut bids, .. } => bids[player] = Bid::Amount(bid),
+            Phase::OpenBid { ref mut high, .. } => *high = Some((player, bid)),
+            _ => return Err(AcquireError::WrongPhase(Action::Bid)),
+        }
+        let mut logs = self.bid_logs(player, Msg::BidPlaced, vec![N::Player(player), amount(bid)]);
+        logs.extend(self.advance_auction()?);
+        Ok((logs, false))
+    }
+
+    pub fn handle_pass_command(&mut self, player: usize) -> Result<(Vec<Log>, bool), AcquireError> {
+        self.ensure_not_finished()?;
+        self.ensure_player_turn(player)?;
+        match self.phase {
+            Phase::SealedBid { ref mut bids, .. } => bids[player] = Bid::Pass,
+            Phase::OpenBid { ref mut passed, .. } => passed.push(player),
+            _ => return Err(AcquireError::WrongPhase(Action::Bid)),
+        }
+        let mut logs = self.bid_logs(player, Msg::PassedBid, vec![N::Player(player)]);
+        logs.extend(self.advance_auction()?);
+        Ok((logs, false))
+    }
+
+    /// Open bids are announced to everyone, sealed bids only to the bidder.
+    fn bid_logs(&self, player: usize, msg: Msg, args: Vec<N>) -> Vec<Log> {
+        match self.phase {
+            Phase::SealedBid { .. } => {
+                let mut logs =
+                    self.public_logs(|l| l.fill(Msg::SealedBidMade, vec![N::Player(player)]));
+                logs.extend(self.private_logs(&[player], |l| l.fill(msg, args.clone())));
+                logs
+            }
+            _ => self.public_logs(|l| l.fill(msg, args.clone())),
+        }
+    }
+
+    /// Move on to the next bidder, or settle the auction once bidding is over.
+    fn advance_auction(&mut self) -> Result<Vec<Log>, AcquireError> {
+        let players = self.players.len();
+        match self.phase.clone() {
+            Phase::SealedBid { player, corp, bids } => {
+                if bids.contains(&Bid::Pending) {
+                    return Ok(vec![]);
+                }
+                // The highest bid wins, with ties going to whoever is first in turn order.
+                let mut winner: Option<(usize, usize)> = None;
+                for offset in 0..players {
+                    let p = (player + offset) % players;
+                    if let Bid::Amount(a) = bids[p] {
+                        if winner.map(|(_, w)| a > w).unwrap_or(true) {
+                            winner = Some((p, a));
+                        }
+                    }
+                }
+                let mut logs = self.public_logs(|l| {
+                    l.fill(
+                        Msg::SealedBids,
+                        vec![comma_list(
+                            bids.iter()
+                                .enumerate()
+                                .map(|(p, bid)| {
+                                    N::Group(vec![
+                                        N::Player(p),
+                                        N::text(" "),
+                                        match *bid {
+                                            Bid::Amount(a) => amount(a),
+                                            _ => N::text(l.template(Msg::BidPassed)),
+                                        },
+                                    ])
+                                })
+                                .collect(),
+                        )],
+                    )
+                });
+                logs.extend(self.end_auction(player, corp, winner)?);
+                Ok(logs)
+            }
+            Phase::OpenBid {
+                player,
+                corp,
+                bidder,
+                high,
+                passed,
+            } => {
+                let next = (1..players + 1)
+                    .map(|offset| (bidder + offset) % players)
+                    .find(|p| !passed.contains(p));
+                match next {
+                    Some(p) if high.map(|(h, _)| h != p).unwrap_or(true) => {
+                        self.phase = Phase::OpenBid {
+                            player,
+                            corp,
+                            bidder: p,
+                            high,
+                            passed,
+                        };
+                        Ok(vec![])
+                    }
+                    // Everyone else has dropped out, or nobody bid at all.
+                    _ => self.end_auction(player, corp, high),
+                }
+            }
+            _ => Ok(vec![]),
+        }
+    }
+
+    fn end_auction(
+        &mut self,
+        player: usize,
+        corp: Corp,
+        winner: Option<(usize, usize)>,
+    ) -> Result<Vec<Log>, AcquireError> {
+        let logs = match winner {
+            Some((p, bid)) => {
+                let n = self.auction_shares(&corp);
+                self.players[p].money -= bid;
+                self.bank_receive(bid);
+                self.take_shares(p, n, &corp)?;
+                self.market_buy(&corp, n);
+                self.record_buy(corp, n);
+                self.players[p].stats.buy_sum += bid;
+                self.players[p].stats.buys += n;
+                self.public_logs(|l| {
+                    l.fill(
+                        Msg::AuctionWon,
+                        vec![
+                            N::Player(p),
+                            N::Bold(vec![N::text(format!("{}", n))]),
+                            self.render_corp(&corp),
+                            amount(bid),
+                        ],
+                    )
+                })
+            }
+            None => {
+                self.public_logs(|l| l.fill(Msg::AuctionNoBids, vec![self.render_corp(&corp)]))
+            }
+        };
+        self.buy_phase(player);
+        Ok(logs)
+    }
+}
+
+impl PubState {
+    /// A summary of the auction in progress, if there is one.
+    pub fn render_auction_text(&self, locale: Locale) -> Option<N> {
+        match self.phase {
+            Phase::SealedBid { corp, ref bids, .. } => Some(N::Group(locale.fill(
+                Msg::AuctionWaiting,
+                vec![
+                    self.roster.render(&corp),
+                    comma_list(
+                        (0..bids.len())
+                            .filter(|p| bids[*p] == Bid::Pending)
+                            .map(N::Player)
+                            .collect(),
+                    ),
+                ],
+            ))),
+            Phase::OpenBid { corp, high, .. } => Some(N::Group(match high {
+                Some((p, bid)) => locale.fill(
+                    Msg::AuctionHighBid,
+                    vec![self.roster.render(&corp), amount(bid), N::Player(p)],
+                ),
+                None => locale.fill(Msg::AuctionOpening, vec![self.roster.render(&corp)]),
+            })),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use brdgme_game::Gamer;
+
+    use crate::corp::Corp;
+    use crate::options::{AuctionRule, AuctionStyle};
+    use crate::{Game, Phase, STARTING_MONEY};
+
+    #[test]
+    fn sealed_auction_goes_to_highest_bid() {
+        let players: Vec<String> = (0..3).map(|p| format!("p{}", p)).collect();
+        let mut g: Game = "AA0
+                           ..1
+                           ..2"
+            .into();
+        g.options.auction = Some(AuctionRule {
+            style: AuctionStyle::Sealed,
+            shares: 3,
+        });
+        g.start_auction(0, Corp::American);
+        assert_eq!(vec![0, 1, 2], g.phase.acting());
+        g.command(1, "bid 500", &players)
+            .expect("expected 'bid 500' to work");
+        g.command(0, "bid 700", &players)
+            .expect("expected 'bid 700' to work");
+        assert_eq!(vec![2], g.phase.acting());
+        g.command(2, "pass", &players)
+            .expect("expected 'pass' to work");
+        assert_eq!(
+            Phase::Buy {
+                player: 0,
+                remaining: 3,
+            },
+            g.phase
+        );
+        assert_eq!(Some(&3), g.players[0].shares.get(&Corp::American));
+        assert_eq!(STARTING_MONEY - 700, g.players[0].money);
+    }
+
+    #[test]
+    fn open_auction_ends_when_others_pass() {
+        let players: Vec<String> = (0..3).map(|p| format!("p{}", p)).collect();
+        let mut g: Game = "AA0
+                           ..1
+                           ..2"
+            .into();
+        g.options.auction = Some(AuctionRule {
+            style: AuctionStyle::Open,
+            shares: 2,
+        });
+        g.start_auction(0, Corp::American);
+        g.command(0, "bid 100", &players)
+            .expect("expected 'bid 100' to work");
+        g.command(1, "bid 300", &players)
+            .expect("expected 'bid 300' to work");
+        g.command(2, "pass", &players)
+            .expect("expected 'pass' to work");
+        g.command(0, "pass", &players)
+            .expect("expected 'pass' to work");
+        assert_eq!(Some(&2), g.players[1].shares.get(&Corp::American));
+        assert_eq!(STARTING_MONEY - 300, g.players[1].money);
+    }
+}
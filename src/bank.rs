@@ -0,0 +1,130 @@
+use brdgme_game::Log;
+use brdgme_markup::Node as N;
+
+use crate::errors::AcquireError;
+use crate::i18n::Msg;
+use crate::options::{BankLimit, Shortfall};
+use crate::visibility::redacted;
+use crate::{Game, BONUS_ROUNDING, STARTING_MONEY};
+
+impl BankLimit {
+    /// The bank's balance once each player has been given their starting cash.
+    pub fn opening_balance(&self, players: usize) -> Result<usize, AcquireError> {
+        let needed = players * STARTING_MONEY;
+        self.total
+            .checked_sub(needed)
+            .ok_or(AcquireError::BankTooSmall { needed })
+    }
+}
+
+impl Game {
+    /// Money paid into the bank, which is only tracked when the bank is limited.
+    pub(crate) fn bank_receive(&mut self, amount: usize) {
+        if let Some(ref mut balance) = self.bank {
+            *balance += amount;
+        }
+    }
+
+    /// Take amounts owed to players out of the bank, in order of priority. Returns what is
+    /// actually paid for each amount, which is less than owed once the bank runs short.
+    pub(crate) fn bank_pay(&mut self, owed: &[(usize, usize)]) -> (Vec<usize>, Vec<Log>) {
+        let total: usize = owed.iter().map(|&(_, amount)| amount).sum();
+        let (balance, shortfall) = match (self.bank, self.options.bank) {
+            (Some(balance), Some(limit)) if balance < total => (balance, limit.shortfall),
+            _ => {
+                if let Some(ref mut balance) = self.bank {
+                    *balance -= total;
+                }
+                return (owed.iter().map(|&(_, amount)| amount).collect(), vec![]);
+            }
+        };
+        let paid: Vec<usize> = match shortfall {
+            Shortfall::Capped => {
+                let mut left = balance;
+                owed.iter()
+                    .map(|&(_, amount)| {
+                        let p = amount.min(left);
+                        left -= p;
+                        p
+                    })
+                    .collect()
+            }
+            // Rounded down so the bank never pays more than it holds.
+            Shortfall::ProRata => owed.iter()
+                .map(|&(_, amount)| amount * balance / total / BONUS_ROUNDING * BONUS_ROUNDING)
+                .collect(),
+        };
+        let paid_total: usize = paid.iter().sum();
+        self.bank = Some(balance - paid_total);
+        let recipients: Vec<usize> = owed.iter().map(|&(p, _)| p).collect();
+        let logs = self.redacted_logs(&recipients, !self.money_visible(), |l, shown| {
+            let amount = |a: usize| {
+                if shown {
+                    N::Bold(vec![N::text(format!("${}", a))])
+                } else {
+                    redacted()
+                }
+            };
+            l.fill(Msg::BankShortfall, vec![amount(paid_total), amount(total)])
+        });
+        (paid, logs)
+    }
+
+    /// All money held by the players and the bank. This never changes when the bank is
+    /// limited.
+    pub fn total_money(&self) -> usize {
+        self.bank.unwrap_or(0) + self.players.iter().map(|p| p.money).sum::<usize>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use brdgme_game::Gamer;
+
+    use crate::corp::Corp;
+    use crate::errors::AcquireError;
+    use crate::options::{BankLimit, Shortfall};
+    use crate::{Game, STARTING_MONEY};
+
+    #[test]
+    fn bank_must_cover_starting_cash() {
+        let limit = BankLimit {
+            total: STARTING_MONEY,
+            shortfall: Shortfall::Capped,
+        };
+        assert_eq!(
+            Err(AcquireError::BankTooSmall {
+                needed: 2 * STARTING_MONEY,
+            }),
+            limit.opening_balance(2)
+        );
+    }
+
+    #[test]
+    fn bank_shortfall_is_paid_pro_rata() {
+        let players = vec!["mick".to_string(), "steve".to_string()];
+        let mut g: Game = "FF0
+                           ..A
+                           ..A"
+            .into();
+        g.options.bank = Some(BankLimit {
+            total: 2 * STARTING_MONEY + 3000,
+            shortfall: Shortfall::ProRata,
+        });
+        g.bank = Some(3000);
+        g.players[0].shares.insert(Corp::American, 9);
+        g.players[1].shares.insert(Corp::American, 8);
+        let total = g.total_money();
+        g.command(0, "play a3", &players)
+            .expect("expected 'play a3' to work");
+        g.command(0, "merge am into fe", &players)
+            .expect("expected 'merge am into fe' to work");
+        assert_eq!(STARTING_MONEY + 2000, g.players[0].money);
+        assert_eq!(STARTING_MONEY + 1000, g.players[1].money);
+        assert_eq!(Some(0), g.bank);
+        g.command(0, "sell 9", &players)
+            .expect("expected 'sell 9' to work");
+        assert_eq!(STARTING_MONEY + 2000, g.players[0].money);
+        assert_eq!(total, g.total_money());
+    }
+}
@@ -1,6 +1,5 @@
 use serde_derive::{Serialize, Deserialize};
 
-use brdgme_game::errors::GameError;
 use brdgme_markup::Node as N;
 
 use std::iter::{self, FromIterator};
@@ -9,6 +8,7 @@ use std::fmt;
 use std::collections::HashSet;
 
 use crate::corp::{self, Corp};
+use crate::errors::AcquireError;
 
 pub const WIDTH: usize = 12;
 pub const HEIGHT: usize = 9;
@@ -28,26 +28,213 @@ impl Default for Tile {
     }
 }
 
+/// The outcome of playing a tile at a location.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TileEffect {
+    /// There is already a tile at the location.
+    Occupied,
+    /// The tile would merge multiple safe corporations, which is never allowed.
+    MergesSafeCorps(Vec<Corp>),
+    /// The tile would found a corporation but all of them are already on the board.
+    NoCorpAvailable,
+    /// The tile doesn't touch anything and stays unincorporated.
+    Unincorporated,
+    Found,
+    Grow(Corp),
+    /// Corporations in `from` are candidates to be merged into those in `into`.
+    Merge { from: Vec<Corp>, into: Vec<Corp> },
+}
+
+impl TileEffect {
+    pub fn is_playable(&self) -> bool {
+        match *self {
+            TileEffect::Occupied | TileEffect::MergesSafeCorps(_) | TileEffect::NoCorpAvailable => {
+                false
+            }
+            _ => true,
+        }
+    }
+}
+
+/// Boards load from either layout they have been saved in, so games stored before the board
+/// held its corporations and geometry still deserialize without going through `from_save`.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct Board(pub Vec<Tile>);
+#[serde(from = "SavedBoard")]
+pub struct Board {
+    pub tiles: Vec<Tile>,
+    /// The corporations which can be founded, from the game's roster.
+    pub corps: Vec<Corp>,
+    pub geometry: Geometry,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SavedBoard {
+    /// The original layout, which was just the tiles of the classic board.
+    Tiles(Vec<Tile>),
+    Board {
+        tiles: Vec<Tile>,
+        #[serde(default = "all_corps")]
+        corps: Vec<Corp>,
+        #[serde(default)]
+        geometry: Geometry,
+    },
+}
+
+impl From<SavedBoard> for Board {
+    fn from(saved: SavedBoard) -> Self {
+        match saved {
+            SavedBoard::Tiles(tiles) => Board {
+                tiles,
+                corps: all_corps(),
+                geometry: Geometry::default(),
+            },
+            SavedBoard::Board {
+                tiles,
+                corps,
+                geometry,
+            } => Board {
+                tiles,
+                corps,
+                geometry,
+            },
+        }
+    }
+}
+
+/// The shape of the board. Tiles are stored row by row across the full rectangle, including
+/// masked cells.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Geometry {
+    pub width: usize,
+    pub height: usize,
+    /// Cells which aren't part of the board, for irregular maps.
+    #[serde(default)]
+    pub masked: Vec<Loc>,
+    /// Whether cells are hexagons, with odd rows offset half a cell to the right.
+    #[serde(default)]
+    pub hex: bool,
+}
+
+impl Default for Geometry {
+    fn default() -> Self {
+        Geometry::rect(WIDTH, HEIGHT)
+    }
+}
+
+impl Geometry {
+    pub fn rect(width: usize, height: usize) -> Self {
+        Geometry {
+            width,
+            height,
+            masked: vec![],
+            hex: false,
+        }
+    }
+
+    /// A smaller board for quick two player games.
+    pub fn small() -> Self {
+        Geometry::rect(8, 6)
+    }
+
+    pub fn contains(&self, loc: &Loc) -> bool {
+        loc.row < self.height && loc.col < self.width && !self.masked.contains(loc)
+    }
+
+    /// Every cell on the board, row by row.
+    pub fn locs(&self) -> Vec<Loc> {
+        (0..self.height)
+            .flat_map(move |row| (0..self.width).map(move |col| Loc { row, col }))
+            .filter(|l| !self.masked.contains(l))
+            .collect()
+    }
+
+    pub fn neighbours(&self, loc: &Loc) -> Vec<Loc> {
+        let (row, col) = (loc.row as isize, loc.col as isize);
+        let mut offsets: Vec<(isize, isize)> = vec![(0, -1), (0, 1), (-1, 0), (1, 0)];
+        if self.hex {
+            // Hexagons touch a second cell in the rows above and below, on the side their row
+            // is offset towards.
+            let side = if loc.row % 2 == 0 { -1 } else { 1 };
+            offsets.push((-1, side));
+            offsets.push((1, side));
+        }
+        offsets
+            .into_iter()
+            .filter(|&(r, c)| row + r >= 0 && col + c >= 0)
+            .map(|(r, c)| Loc {
+                row: (row + r) as usize,
+                col: (col + c) as usize,
+            })
+            .filter(|l| self.contains(l))
+            .collect()
+    }
+
+    /// Where a location is in the board's list of tiles.
+    pub fn index(&self, loc: &Loc) -> usize {
+        loc.row * self.width + loc.col
+    }
+
+    /// The location at a position in the board's list of tiles.
+    pub fn loc(&self, index: usize) -> Loc {
+        Loc {
+            row: index / self.width,
+            col: index % self.width,
+        }
+    }
+
+    /// Check the board has enough cells to deal the starting tiles and hands.
+    pub fn validate(&self, needed: usize) -> Result<(), AcquireError> {
+        if self.locs().len() < needed {
+            return Err(AcquireError::BoardTooSmall { needed });
+        }
+        Ok(())
+    }
+}
+
+fn all_corps() -> Vec<Corp> {
+    Corp::iter().cloned().collect()
+}
 
 impl Board {
-    pub fn get_tile<T: Into<usize>>(&self, at: T) -> Tile {
-        self.0.get(at.into()).cloned().unwrap_or_default()
+    pub fn new(geometry: Geometry, corps: Vec<Corp>) -> Self {
+        Board {
+            tiles: iter::repeat(Tile::default())
+                .take(geometry.width * geometry.height)
+                .collect(),
+            corps,
+            geometry,
+        }
     }
 
-    pub fn set_tile<T: Into<usize>>(&mut self, at: T, t: Tile) {
-        let len = self.0.len();
-        let at_u = at.into();
+    pub fn get_tile<T: Into<Loc>>(&self, at: T) -> Tile {
+        let loc = at.into();
+        if !self.geometry.contains(&loc) {
+            return Tile::default();
+        }
+        self.tiles
+            .get(self.geometry.index(&loc))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Set a tile, ignoring locations which aren't on the board.
+    pub fn set_tile<T: Into<Loc>>(&mut self, at: T, t: Tile) {
+        let loc = at.into();
+        if !self.geometry.contains(&loc) {
+            return;
+        }
+        let len = self.tiles.len();
+        let at_u = self.geometry.index(&loc);
         if len <= at_u {
-            self.0
+            self.tiles
                 .extend(iter::repeat(Tile::default()).take(at_u - len + 1))
         }
-        self.0[at_u] = t;
+        self.tiles[at_u] = t;
     }
 
     pub fn corp_size(&self, c: &Corp) -> usize {
-        self.0
+        self.tiles
             .iter()
             .filter(|t| match **t {
                 Tile::Corp(tc) if tc == *c => true,
@@ -61,8 +248,8 @@ impl Board {
     }
 
     pub fn available_corps(&self) -> HashSet<Corp> {
-        let mut corps: HashSet<Corp> = HashSet::from_iter(Corp::iter().cloned());
-        for l in &Loc::all() {
+        let mut corps: HashSet<Corp> = HashSet::from_iter(self.corps.iter().cloned());
+        for l in &self.geometry.locs() {
             if let Tile::Corp(c) = self.get_tile(l) {
                 corps.remove(&c);
             }
@@ -72,7 +259,7 @@ impl Board {
 
     pub fn neighbouring_corps(&self, loc: &Loc) -> HashSet<Corp> {
         let mut corps: HashSet<Corp> = HashSet::new();
-        for n_loc in &loc.neighbours() {
+        for n_loc in &self.geometry.neighbours(loc) {
             if let Tile::Corp(c) = self.get_tile(n_loc) {
                 corps.insert(c);
             }
@@ -119,7 +306,7 @@ impl Board {
 
     pub fn extend_corp(&mut self, loc: &Loc, corp: &Corp) {
         self.set_tile(loc, Tile::Corp(corp.to_owned()));
-        for n_loc in &loc.neighbours() {
+        for n_loc in &self.geometry.neighbours(loc) {
             if self.get_tile(n_loc) == Tile::Unincorporated {
                 self.extend_corp(n_loc, corp);
             }
@@ -127,7 +314,7 @@ impl Board {
     }
 
     pub fn convert_corp(&mut self, from: &Corp, into: &Corp) {
-        for loc in &Loc::all() {
+        for loc in &self.geometry.locs() {
             match self.get_tile(loc) {
                 Tile::Corp(c) if c == *from => self.set_tile(loc, Tile::Corp(*into)),
                 _ => {}
@@ -135,23 +322,25 @@ impl Board {
         }
     }
 
-    pub fn assert_loc_playable(&self, loc: &Loc) -> Result<(), GameError> {
+    pub fn assert_loc_playable(&self, loc: &Loc) -> Result<(), AcquireError> {
         if self.loc_neighbours_multiple_safe_corps(loc) {
-            return Err(GameError::InvalidInput {
-                message: "can't merge multiple safe corporations".to_string(),
-            });
+            let neighbouring_corps = self.neighbouring_corps(loc);
+            return Err(AcquireError::WouldMergeSafeCorps(
+                Corp::iter()
+                    .filter(|c| neighbouring_corps.contains(c) && self.corp_is_safe(c))
+                    .cloned()
+                    .collect(),
+            ));
         }
         if self.loc_founds(loc) && self.available_corps().is_empty() {
-            return Err(GameError::InvalidInput {
-                message: "there are no available unincorporated corporations".to_string(),
-            });
+            return Err(AcquireError::NoCorpAvailable);
         }
         Ok(())
     }
 
     pub fn loc_founds(&self, loc: &Loc) -> bool {
         let mut has_unincorporated = false;
-        for n_loc in &loc.neighbours() {
+        for n_loc in &self.geometry.neighbours(loc) {
             match self.get_tile(n_loc) {
                 Tile::Unincorporated => has_unincorporated = true,
                 Tile::Corp(_) => return false,
@@ -174,6 +363,43 @@ impl Board {
         false
     }
 
+    /// Describe what playing a tile at the location would do, without modifying the board.
+    pub fn tile_effect(&self, loc: &Loc) -> TileEffect {
+        if self.get_tile(loc) != Tile::Empty {
+            return TileEffect::Occupied;
+        }
+        let neighbouring_corps = self.neighbouring_corps(loc);
+        let corps: Vec<Corp> = Corp::iter()
+            .filter(|c| neighbouring_corps.contains(c))
+            .cloned()
+            .collect();
+        match corps.len() {
+            0 => {
+                if !self.loc_founds(loc) {
+                    TileEffect::Unincorporated
+                } else if self.available_corps().is_empty() {
+                    TileEffect::NoCorpAvailable
+                } else {
+                    TileEffect::Found
+                }
+            }
+            1 => TileEffect::Grow(corps[0]),
+            _ => {
+                let safe: Vec<Corp> = corps
+                    .iter()
+                    .filter(|c| self.corp_is_safe(c))
+                    .cloned()
+                    .collect();
+                if safe.len() > 1 {
+                    TileEffect::MergesSafeCorps(safe)
+                } else {
+                    let (from, into) = self.merge_candidates(loc);
+                    TileEffect::Merge { from, into }
+                }
+            }
+        }
+    }
+
     pub fn set_discarded(&mut self, locs: &[Loc]) {
         for loc in locs {
             self.set_tile(loc, Tile::Discarded);
@@ -210,7 +436,7 @@ impl<'a> From<&'a str> for Board {
 
 impl Default for Board {
     fn default() -> Self {
-        Board(iter::repeat(Tile::default()).take(SIZE).collect())
+        Board::new(Geometry::default(), all_corps())
     }
 }
 
@@ -229,39 +455,14 @@ pub struct Loc {
 }
 
 impl Loc {
+    /// Every cell on the standard board.
     pub fn all() -> Vec<Loc> {
-        rows()
-            .flat_map(move |r| cols().map(move |c| Loc { row: r, col: c }))
-            .collect()
+        Geometry::default().locs()
     }
 
+    /// Neighbouring cells on the standard board.
     pub fn neighbours(&self) -> Vec<Loc> {
-        let mut n = vec![];
-        if self.col > 0 {
-            n.push(Loc {
-                col: self.col - 1,
-                ..*self
-            });
-        }
-        if self.col < WIDTH - 1 {
-            n.push(Loc {
-                col: self.col + 1,
-                ..*self
-            });
-        }
-        if self.row > 0 {
-            n.push(Loc {
-                row: self.row - 1,
-                ..*self
-            });
-        }
-        if self.row < HEIGHT - 1 {
-            n.push(Loc {
-                row: self.row + 1,
-                ..*self
-            });
-        }
-        n
+        Geometry::default().neighbours(self)
     }
 
     pub fn name(&self) -> String {
@@ -273,30 +474,27 @@ impl Loc {
     }
 }
 
-impl fmt::Display for Loc {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}{}", (b'A' + self.row as u8) as char, self.col + 1)
+/// Row names run A to Z, then AA, AB and so on.
+fn row_name(row: usize) -> String {
+    let mut letters: Vec<char> = vec![];
+    let mut n = row + 1;
+    while n > 0 {
+        n -= 1;
+        letters.push((b'A' + (n % 26) as u8) as char);
+        n /= 26;
     }
+    letters.into_iter().rev().collect()
 }
 
-impl From<usize> for Loc {
-    fn from(u: usize) -> Self {
-        Loc {
-            row: u / WIDTH,
-            col: u % WIDTH,
-        }
+impl fmt::Display for Loc {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", row_name(self.row), self.col + 1)
     }
 }
 
-impl<'a> From<&'a Loc> for usize {
+impl<'a> From<&'a Loc> for Loc {
     fn from(l: &Loc) -> Self {
-        l.row * WIDTH + l.col
-    }
-}
-
-impl From<Loc> for usize {
-    fn from(l: Loc) -> Self {
-        (&l).into()
+        *l
     }
 }
 
@@ -306,27 +504,32 @@ mod tests {
     use crate::corp::Corp;
 
     #[test]
-    fn usize_into_loc_works() {
-        assert_eq!(Loc::default(), 0.into());
-        assert_eq!(Loc { row: 0, col: 8 }, 8.into());
-        assert_eq!(Loc { row: 2, col: 3 }, 27.into());
-        assert_eq!(Loc { row: 1, col: 11 }, 23.into());
+    fn index_to_loc_works() {
+        let g = Geometry::default();
+        assert_eq!(Loc::default(), g.loc(0));
+        assert_eq!(Loc { row: 0, col: 8 }, g.loc(8));
+        assert_eq!(Loc { row: 2, col: 3 }, g.loc(27));
+        assert_eq!(Loc { row: 1, col: 11 }, g.loc(23));
+        assert_eq!(Loc { row: 2, col: 7 }, Geometry::small().loc(23));
     }
 
     #[test]
-    fn loc_into_usize_works() {
-        assert_eq!(0 as usize, Loc::default().into());
-        assert_eq!(8 as usize, Loc { row: 0, col: 8 }.into());
-        assert_eq!(27 as usize, Loc { row: 2, col: 3 }.into());
-        assert_eq!(23 as usize, Loc { row: 1, col: 11 }.into());
+    fn loc_to_index_works() {
+        let g = Geometry::default();
+        assert_eq!(0, g.index(&Loc::default()));
+        assert_eq!(8, g.index(&Loc { row: 0, col: 8 }));
+        assert_eq!(27, g.index(&Loc { row: 2, col: 3 }));
+        assert_eq!(23, g.index(&Loc { row: 1, col: 11 }));
+        assert_eq!(23, Geometry::small().index(&Loc { row: 2, col: 7 }));
     }
 
     #[test]
     fn board_get_tile_works() {
         let mut b = Board::default();
-        b.set_tile(5usize, Tile::Discarded);
-        assert_eq!(Tile::Discarded, b.get_tile(5usize));
-        assert_eq!(Tile::Empty, b.get_tile(99999usize));
+        let at = b.geometry.loc(5);
+        b.set_tile(at, Tile::Discarded);
+        assert_eq!(Tile::Discarded, b.get_tile(at));
+        assert_eq!(Tile::Empty, b.get_tile(b.geometry.loc(99999)));
     }
 
     #[test]
@@ -338,17 +541,61 @@ mod tests {
     #[test]
     fn board_set_tile_works() {
         let mut b = Board::default();
-        b.set_tile(99999usize, Tile::Unincorporated);
+        let at = b.geometry.loc(99999);
+        b.set_tile(at, Tile::Unincorporated);
+    }
+
+    #[test]
+    fn geometry_works() {
+        let mut g = Geometry::rect(30, 30);
+        g.masked = vec![Loc { row: 0, col: 1 }];
+        g.hex = true;
+        assert_eq!(899, g.locs().len());
+        assert_eq!("AA30", Loc { row: 26, col: 29 }.name());
+        assert_eq!(
+            vec![Loc { row: 1, col: 0 }],
+            g.neighbours(&Loc { row: 0, col: 0 })
+        );
+        assert_eq!(6, g.neighbours(&Loc { row: 3, col: 1 }).len());
+        assert!(g.neighbours(&Loc { row: 3, col: 1 }).contains(&Loc { row: 2, col: 2 }));
+        let b = Board::new(g, all_corps());
+        assert_eq!(Tile::Empty, b.get_tile(Loc { row: 0, col: 1 }));
+        assert!(Geometry::small().validate(2 * 7).is_ok());
+        assert!(Geometry::rect(3, 3).validate(14).is_err());
     }
 
     #[test]
     fn board_corp_size_works() {
         let mut b = Board::default();
-        b.set_tile(2usize, Tile::Corp(Corp::American));
-        b.set_tile(3usize, Tile::Corp(Corp::American));
-        b.set_tile(4usize, Tile::Corp(Corp::Sackson));
+        b.set_tile(Loc { row: 0, col: 2 }, Tile::Corp(Corp::American));
+        b.set_tile(Loc { row: 0, col: 3 }, Tile::Corp(Corp::American));
+        b.set_tile(Loc { row: 0, col: 4 }, Tile::Corp(Corp::Sackson));
         assert_eq!(0, b.corp_size(&Corp::Continental));
         assert_eq!(1, b.corp_size(&Corp::Sackson));
         assert_eq!(2, b.corp_size(&Corp::American));
     }
+
+    #[test]
+    fn board_tile_effect_works() {
+        let b: Board = "#.AA
+                        ..S.
+                        ....".into();
+        assert_eq!(TileEffect::Occupied, b.tile_effect(&Loc { row: 0, col: 2 }));
+        assert_eq!(TileEffect::Found, b.tile_effect(&Loc { row: 1, col: 0 }));
+        assert_eq!(
+            TileEffect::Grow(Corp::American),
+            b.tile_effect(&Loc { row: 0, col: 1 })
+        );
+        assert_eq!(
+            TileEffect::Merge {
+                from: vec![Corp::Sackson],
+                into: vec![Corp::American],
+            },
+            b.tile_effect(&Loc { row: 1, col: 3 })
+        );
+        assert_eq!(
+            TileEffect::Unincorporated,
+            b.tile_effect(&Loc { row: 2, col: 0 })
+        );
+    }
 }
@@ -1,8 +1,11 @@
 use brdgme_game::command::parser::*;
 use brdgme_game::Gamer;
 
-use crate::board::Loc;
-use crate::corp::{Corp, CORPS};
+use crate::board::{Geometry, Loc};
+use crate::corp::Corp;
+use crate::i18n::{Locale, Msg, LOCALES};
+use crate::roster::Roster;
+use crate::seat::Seat;
 use crate::Game;
 use crate::Phase;
 
@@ -10,6 +13,7 @@ use std::usize;
 
 pub enum Command {
     Play(Loc),
+    Discard(Loc),
     Found(Corp),
     Buy(usize, Corp),
     Done,
@@ -17,22 +21,60 @@ pub enum Command {
     Sell(usize),
     Trade(usize),
     Keep,
+    Bid(usize),
+    Pass,
+    Borrow,
+    Repay,
     End,
+    Resign,
+    Undo,
+    Info(Corp),
+    Tile(Loc),
+    Majority(Corp),
+    Deck,
+    History,
+    Timeline,
+    Language(Locale),
+}
+
+impl Command {
+    /// Whether the command changes the game in a way which the player could later undo.
+    pub fn is_undoable(&self) -> bool {
+        match *self {
+            Command::Undo
+            | Command::Info(_)
+            | Command::Tile(_)
+            | Command::Majority(_)
+            | Command::Deck
+            | Command::History
+            | Command::Timeline
+            | Command::Language(_) => false,
+            _ => true,
+        }
+    }
 }
 
 impl Game {
+    /// The commands a player can enter, or `None` if they can't enter any because the game has
+    /// finished or they aren't in it. Queries are available to players in the game at any time,
+    /// so this is `Some` even when it isn't the player's turn.
     pub fn command_parser(&self, player: usize) -> Option<Box<Parser<Command>>> {
-        if self.is_finished() {
+        if self.is_finished() || player >= self.players.len() {
             return None;
         }
         let mut parsers: Vec<Box<Parser<Command>>> = vec![];
-        if self.phase.whose_turn() == player {
+        let l = self.locale(player);
+        if self.phase.acting().contains(&player) {
             match self.phase {
                 Phase::Play(_) => {
                     parsers.push(Box::new(self.play_parser(player)));
+                    if self.can_discard(player) {
+                        parsers.push(Box::new(self.discard_parser(player)));
+                    }
                 }
                 Phase::Found { .. } => {
                     parsers.push(Box::new(self.found_parser(
+                        l,
                         self.board.available_corps().into_iter().collect(),
                     )));
                 }
@@ -40,11 +82,11 @@ impl Game {
                     if remaining > 0 {
                         parsers.push(Box::new(self.buy_parser(player, remaining)));
                     }
-                    parsers.push(Box::new(done_parser()));
+                    parsers.push(Box::new(done_parser(l)));
                 }
                 Phase::ChooseMerger { at, .. } => {
                     parsers.push(Box::new(
-                        self.merge_parser(&self.board
+                        self.merge_parser(l, &self.board
                             .neighbouring_corps(&at)
                             .into_iter()
                             .collect::<Vec<Corp>>()),
@@ -59,24 +101,54 @@ impl Game {
                     {
                         parsers.push(Box::new(self.trade_parser(player, corp)));
                     }
-                    parsers.push(Box::new(keep_parser()));
+                    parsers.push(Box::new(keep_parser(l)));
                 }
+                Phase::SealedBid { .. } | Phase::OpenBid { .. } => {
+                    if let Some(min) = self.min_bid() {
+                        if min <= self.players[player].money {
+                            parsers.push(Box::new(bid_parser(l, min, self.players[player].money)));
+                        }
+                    }
+                    parsers.push(Box::new(pass_parser(l)));
+                }
+            }
+            if self.can_borrow(player) {
+                parsers.push(Box::new(borrow_parser(l)));
+            }
+            if self.can_repay(player) {
+                parsers.push(Box::new(repay_parser(l)));
             }
             if self.player_can_end(player) {
-                parsers.push(Box::new(end_parser()));
+                parsers.push(Box::new(end_parser(l)));
             }
         }
-        if parsers.is_empty() {
-            None
-        } else {
-            Some(Box::new(OneOf::new(parsers)))
+        if self.players[player].seat == Seat::Human {
+            parsers.push(Box::new(resign_parser(l)));
+        }
+        if self.can_undo(player) {
+            parsers.push(Box::new(undo_parser(l)));
         }
+        // Queries don't modify the game so are available out of turn.
+        parsers.push(Box::new(info_parser(l, &self.options.roster)));
+        parsers.push(Box::new(tile_parser(l, &self.board.geometry)));
+        parsers.push(Box::new(majority_parser(l, &self.options.roster)));
+        parsers.push(Box::new(deck_parser(l)));
+        // There is no report query as finished games take no commands. The report is in the
+        // public state and the render once the game has finished.
+        parsers.push(Box::new(history_parser(l)));
+        parsers.push(Box::new(timeline_parser(l)));
+        parsers.push(Box::new(language_parser(l)));
+        Some(Box::new(OneOf::new(parsers)))
     }
 
     fn play_parser(&self, player: usize) -> impl Parser<Command> {
         Map::new(
             Chain2::new(
-                Doc::name_desc("play", "play a tile to the board", Token::new("play")),
+                Doc::name_desc(
+                    "play",
+                    self.locale(player).template(Msg::DocPlay),
+                    Token::new("play"),
+                ),
                 AfterSpace::new(Doc::name(
                     "tile",
                     Enum::exact(
@@ -91,46 +163,62 @@ impl Game {
         )
     }
 
-    fn found_parser(&self, corps: Vec<Corp>) -> impl Parser<Command> {
+    fn discard_parser(&self, player: usize) -> impl Parser<Command> {
         Map::new(
             Chain2::new(
-                Doc::name_desc("found", "found a new corporation", Token::new("found")),
+                Doc::name_desc(
+                    "discard",
+                    self.locale(player).template(Msg::DocDiscard),
+                    Token::new("discard"),
+                ),
+                AfterSpace::new(Doc::name("tile", Enum::exact(self.dead_tiles(player)))),
+            ),
+            |(_, loc)| Command::Discard(loc),
+        )
+    }
+
+    fn found_parser(&self, l: Locale, corps: Vec<Corp>) -> impl Parser<Command> {
+        Map::new(
+            Chain2::new(
+                Doc::name_desc("found", l.template(Msg::DocFound), Token::new("found")),
                 AfterSpace::new(Doc::name_desc(
                     "corp",
-                    "the corporation to found",
-                    Enum::partial(corps),
+                    l.template(Msg::DocFoundCorp),
+                    Enum::partial(self.options.roster.names(&corps)),
                 )),
             ),
-            |(_, corp)| Command::Found(corp),
+            |(_, corp)| Command::Found(corp.corp),
         )
     }
 
-    fn buy_parser(&self, _player: usize, remaining: usize) -> impl Parser<Command> {
+    fn buy_parser(&self, player: usize, remaining: usize) -> impl Parser<Command> {
+        let l = self.locale(player);
         Map::new(
             Chain3::new(
-                Doc::name_desc("buy", "buy shares", Token::new("buy")),
+                Doc::name_desc("buy", l.template(Msg::DocBuy), Token::new("buy")),
                 AfterSpace::new(Doc::name_desc(
                     "#",
-                    "number of shares to buy",
+                    l.template(Msg::DocBuyAmount),
                     Int::bounded(1, remaining as i32),
                 )),
                 AfterSpace::new(Doc::name_desc(
                     "corp",
-                    "the corporation to buy shares in",
-                    Enum::partial(CORPS.to_vec()),
+                    l.template(Msg::DocBuyCorp),
+                    Enum::partial(self.options.roster.names(&self.options.roster.corps())),
                 )),
             ),
-            |(_, n, corp)| Command::Buy(n as usize, corp),
+            |(_, n, corp)| Command::Buy(n as usize, corp.corp),
         )
     }
 
     fn sell_parser(&self, player: usize, corp: Corp) -> impl Parser<Command> {
+        let l = self.locale(player);
         Map::new(
             Chain2::new(
-                Doc::name_desc("sell", "sell shares", Token::new("sell")),
+                Doc::name_desc("sell", l.template(Msg::DocSell), Token::new("sell")),
                 AfterSpace::new(Doc::name_desc(
                     "#",
-                    "number of shares to sell",
+                    l.template(Msg::DocSellAmount),
                     self.player_shares_parser(player, corp),
                 )),
             ),
@@ -139,12 +227,13 @@ impl Game {
     }
 
     fn trade_parser(&self, player: usize, corp: Corp) -> impl Parser<Command> {
+        let l = self.locale(player);
         Map::new(
             Chain2::new(
-                Doc::name_desc("trade", "trade shares, two-for-one", Token::new("trade")),
+                Doc::name_desc("trade", l.template(Msg::DocTrade), Token::new("trade")),
                 AfterSpace::new(Doc::name_desc(
                     "#",
-                    "number of shares to trade, two-for-one",
+                    l.template(Msg::DocTradeAmount),
                     self.player_shares_parser(player, corp),
                 )),
             ),
@@ -162,51 +251,193 @@ impl Game {
         )
     }
 
-    fn merge_parser(&self, corps: &[Corp]) -> impl Parser<Command> {
+    fn merge_parser(&self, l: Locale, corps: &[Corp]) -> impl Parser<Command> {
         Map::new(
             Chain4::new(
-                Doc::name_desc(
-                    "merge",
-                    "choose which corporation to merge into another",
-                    Token::new("merge"),
-                ),
+                Doc::name_desc("merge", l.template(Msg::DocMerge), Token::new("merge")),
                 AfterSpace::new(Doc::name_desc(
                     "corp",
-                    "the corporation to merge into another",
-                    Enum::partial(corps.to_owned()),
+                    l.template(Msg::DocMergeFrom),
+                    Enum::partial(self.options.roster.names(corps)),
                 )),
                 AfterSpace::new(Token::new("into")),
                 AfterSpace::new(Doc::name_desc(
                     "corp",
-                    "the corporation to be merged into",
-                    Enum::partial(corps.to_owned()),
+                    l.template(Msg::DocMergeInto),
+                    Enum::partial(self.options.roster.names(corps)),
                 )),
             ),
-            |(_, from, _, into)| Command::Merge(from, into),
+            |(_, from, _, into)| Command::Merge(from.corp, into.corp),
         )
     }
 }
 
-fn end_parser() -> impl Parser<Command> {
+fn end_parser(l: Locale) -> impl Parser<Command> {
     Doc::name_desc(
         "end",
-        "trigger the end of the game at the end of your turn",
+        l.template(Msg::DocEnd),
         Map::new(Token::new("end"), |_| Command::End),
     )
 }
 
-fn done_parser() -> impl Parser<Command> {
+fn done_parser(l: Locale) -> impl Parser<Command> {
     Doc::name_desc(
         "done",
-        "finish buying shares and end your turn",
+        l.template(Msg::DocDone),
         Map::new(Token::new("done"), |_| Command::Done),
     )
 }
 
-fn keep_parser() -> impl Parser<Command> {
+fn keep_parser(l: Locale) -> impl Parser<Command> {
     Doc::name_desc(
         "keep",
-        "finish selling and trading shares",
+        l.template(Msg::DocKeep),
         Map::new(Token::new("keep"), |_| Command::Keep),
     )
 }
+
+fn bid_parser(l: Locale, min: usize, max: usize) -> impl Parser<Command> {
+    Map::new(
+        Chain2::new(
+            Doc::name_desc("bid", l.template(Msg::DocBid), Token::new("bid")),
+            AfterSpace::new(Doc::name_desc(
+                "amount",
+                l.template(Msg::DocBidAmount),
+                Int::bounded(min as i32, max as i32),
+            )),
+        ),
+        |(_, n)| Command::Bid(n as usize),
+    )
+}
+
+fn pass_parser(l: Locale) -> impl Parser<Command> {
+    Doc::name_desc(
+        "pass",
+        l.template(Msg::DocPass),
+        Map::new(Token::new("pass"), |_| Command::Pass),
+    )
+}
+
+fn borrow_parser(l: Locale) -> impl Parser<Command> {
+    Doc::name_desc(
+        "borrow",
+        l.template(Msg::DocBorrow),
+        Map::new(Token::new("borrow"), |_| Command::Borrow),
+    )
+}
+
+fn repay_parser(l: Locale) -> impl Parser<Command> {
+    Doc::name_desc(
+        "repay",
+        l.template(Msg::DocRepay),
+        Map::new(Token::new("repay"), |_| Command::Repay),
+    )
+}
+
+fn resign_parser(l: Locale) -> impl Parser<Command> {
+    Doc::name_desc(
+        "resign",
+        l.template(Msg::DocResign),
+        Map::new(Token::new("resign"), |_| Command::Resign),
+    )
+}
+
+fn undo_parser(l: Locale) -> impl Parser<Command> {
+    Doc::name_desc(
+        "undo",
+        l.template(Msg::DocUndo),
+        Map::new(Token::new("undo"), |_| Command::Undo),
+    )
+}
+
+fn info_parser(l: Locale, roster: &Roster) -> impl Parser<Command> {
+    Map::new(
+        Chain2::new(
+            Doc::name_desc(
+                "info",
+                l.template(Msg::DocInfo),
+                Token::new("info"),
+            ),
+            AfterSpace::new(Doc::name_desc(
+                "corp",
+                l.template(Msg::DocQueryCorp),
+                Enum::partial(roster.names(&roster.corps())),
+            )),
+        ),
+        |(_, corp)| Command::Info(corp.corp),
+    )
+}
+
+fn tile_parser(l: Locale, geometry: &Geometry) -> impl Parser<Command> {
+    Map::new(
+        Chain2::new(
+            Doc::name_desc(
+                "tile",
+                l.template(Msg::DocTileQuery),
+                Token::new("tile"),
+            ),
+            AfterSpace::new(Doc::name("tile", Enum::exact(geometry.locs()))),
+        ),
+        |(_, loc)| Command::Tile(loc),
+    )
+}
+
+fn majority_parser(l: Locale, roster: &Roster) -> impl Parser<Command> {
+    Map::new(
+        Chain2::new(
+            Doc::name_desc(
+                "majority",
+                l.template(Msg::DocMajority),
+                Token::new("majority"),
+            ),
+            AfterSpace::new(Doc::name_desc(
+                "corp",
+                l.template(Msg::DocQueryCorp),
+                Enum::partial(roster.names(&roster.corps())),
+            )),
+        ),
+        |(_, corp)| Command::Majority(corp.corp),
+    )
+}
+
+fn deck_parser(l: Locale) -> impl Parser<Command> {
+    Doc::name_desc(
+        "deck",
+        l.template(Msg::DocDeck),
+        Map::new(Token::new("deck"), |_| Command::Deck),
+    )
+}
+
+fn history_parser(l: Locale) -> impl Parser<Command> {
+    Doc::name_desc(
+        "history",
+        l.template(Msg::DocHistory),
+        Map::new(Token::new("history"), |_| Command::History),
+    )
+}
+
+fn timeline_parser(l: Locale) -> impl Parser<Command> {
+    Doc::name_desc(
+        "timeline",
+        l.template(Msg::DocTimeline),
+        Map::new(Token::new("timeline"), |_| Command::Timeline),
+    )
+}
+
+fn language_parser(l: Locale) -> impl Parser<Command> {
+    Map::new(
+        Chain2::new(
+            Doc::name_desc(
+                "language",
+                l.template(Msg::DocLanguage),
+                Token::new("language"),
+            ),
+            AfterSpace::new(Doc::name_desc(
+                "language",
+                l.template(Msg::DocLanguageLocale),
+                Enum::partial(LOCALES.to_vec()),
+            )),
+        ),
+        |(_, locale)| Command::Language(locale),
+    )
+}
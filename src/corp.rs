@@ -6,9 +6,13 @@ use brdgme_markup::Node as N;
 use std::slice::Iter;
 use std::fmt;
 
+use crate::prices::PriceTable;
+
 pub const SAFE_SIZE: usize = 11;
 pub const GAME_END_SIZE: usize = 41;
+#[deprecated(note = "bonuses come from the game's `PriceTable`")]
 pub const MINOR_MULT: usize = 5;
+#[deprecated(note = "bonuses come from the game's `PriceTable`")]
 pub const MAJOR_MULT: usize = 10;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -32,35 +36,21 @@ pub static CORPS: [Corp; 7] = [
     Corp::Tower,
 ];
 
-fn additional_value(size: usize) -> usize {
-    match size {
-        _ if size >= 41 => 800,
-        _ if size >= 31 => 700,
-        _ if size >= 21 => 600,
-        _ if size >= 11 => 500,
-        _ if size >= 6 => 400,
-        _ if size == 5 => 300,
-        _ if size == 4 => 200,
-        _ if size == 3 => 100,
-        _ => 0,
-    }
-}
-
 impl Corp {
     pub fn iter() -> Iter<'static, Corp> {
         CORPS.into_iter()
     }
 
+    /// The share price at size 2 in the classic price table.
+    #[deprecated(note = "use the game's `PriceTable`")]
     pub fn base_value(&self) -> usize {
-        match *self {
-            Corp::Worldwide | Corp::Sackson => 200,
-            Corp::Festival | Corp::Imperial | Corp::American => 300,
-            Corp::Continental | Corp::Tower => 400,
-        }
+        PriceTable::default().base.get(self).cloned().unwrap_or(0)
     }
 
+    /// The share price in the classic price table, which ignores any custom table or market.
+    #[deprecated(note = "use `Game::share_price` or the game's `PriceTable`")]
     pub fn value(&self, size: usize) -> usize {
-        self.base_value() + additional_value(size)
+        PriceTable::default().value(self, size)
     }
 
     pub fn color(&self) -> Color {
@@ -89,12 +79,14 @@ impl Corp {
         ])
     }
 
+    #[deprecated(note = "use the game's `PriceTable`")]
     pub fn minor_bonus(&self, size: usize) -> usize {
-        self.value(size) * MINOR_MULT
+        PriceTable::default().minor_bonus(self, size)
     }
 
+    #[deprecated(note = "use the game's `PriceTable`")]
     pub fn major_bonus(&self, size: usize) -> usize {
-        self.value(size) * MAJOR_MULT
+        PriceTable::default().major_bonus(self, size)
     }
 }
 
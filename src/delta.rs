@@ -0,0 +1,270 @@
+use serde_derive::{Serialize, Deserialize};
+
+use std::collections::HashMap;
+
+use crate::board::{Loc, Tile};
+use crate::corp::Corp;
+use crate::market::Market;
+use crate::report::EndGameReport;
+use crate::{Phase, PubPlayer, PubState};
+
+/// A player whose public state changed.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerDelta {
+    pub player: usize,
+    /// The player's state afterwards.
+    pub state: PubPlayer,
+    /// How much the player's cash changed by, if it was visible before and after.
+    pub money_change: Option<isize>,
+    /// How each holding changed, if holdings were visible before and after.
+    pub share_changes: Vec<(Corp, isize)>,
+}
+
+/// What changed between two public states, so clients can animate the change and be sent a
+/// small update instead of the whole state. Settings which are fixed when the game is created,
+/// such as the roster and price table, are never included.
+#[derive(Default, PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct StateDelta {
+    /// Locations whose tile changed, with the new tile.
+    pub tiles: Vec<(Loc, Tile)>,
+    /// Corporations whose size changed, with their size before and after. These follow from
+    /// the tiles so aren't needed to apply the delta.
+    pub corp_sizes: Vec<(Corp, usize, usize)>,
+    /// Share pool counts which changed, with the new count.
+    pub shares: Vec<(Corp, usize)>,
+    pub players: Vec<PlayerDelta>,
+    pub phase: Option<Phase>,
+    pub remaining_tiles: Option<usize>,
+    pub last_turn: Option<bool>,
+    pub finished: Option<bool>,
+    /// The bank's new balance, if it changed or stopped being visible. `Some(None)` clears it.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "double_option")]
+    pub bank: Option<Option<usize>>,
+    /// The new market, if it changed. `Some(None)` clears it.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "double_option")]
+    pub market: Option<Option<Market>>,
+    /// The new end game report, if it changed. `Some(None)` clears it.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "double_option")]
+    pub end_report: Option<Option<EndGameReport>>,
+}
+
+/// Serde for fields which can be unchanged, cleared or set. A missing field is unchanged and
+/// `null` clears, which a plain `Option<Option<T>>` can't tell apart.
+mod double_option {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<T, S>(value: &Option<Option<T>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: Serializer,
+    {
+        match *value {
+            Some(ref inner) => inner.serialize(serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        Option::deserialize(deserializer).map(Some)
+    }
+}
+
+fn changed<T: Clone + PartialEq>(old: &T, new: &T) -> Option<T> {
+    if old == new {
+        None
+    } else {
+        Some(new.clone())
+    }
+}
+
+fn share_changes(
+    old: &HashMap<Corp, usize>,
+    new: &HashMap<Corp, usize>,
+    corps: &[Corp],
+) -> Vec<(Corp, isize)> {
+    corps
+        .iter()
+        .map(|c| {
+            let before = old.get(c).cloned().unwrap_or(0) as isize;
+            let after = new.get(c).cloned().unwrap_or(0) as isize;
+            (*c, after - before)
+        })
+        .filter(|&(_, change)| change != 0)
+        .collect()
+}
+
+/// Describe what changed from one public state to the next.
+pub fn diff(old: &PubState, new: &PubState) -> StateDelta {
+    let corps = new.roster.corps();
+    StateDelta {
+        tiles: new.board
+            .geometry
+            .locs()
+            .into_iter()
+            .filter_map(|l| {
+                let tile = new.board.get_tile(&l);
+                if old.board.get_tile(&l) == tile {
+                    None
+                } else {
+                    Some((l, tile))
+                }
+            })
+            .collect(),
+        corp_sizes: corps
+            .iter()
+            .map(|c| (*c, old.board.corp_size(c), new.board.corp_size(c)))
+            .filter(|&(_, before, after)| before != after)
+            .collect(),
+        shares: corps
+            .iter()
+            .filter_map(|c| {
+                let n = new.shares.get(c).cloned().unwrap_or(0);
+                if old.shares.get(c).cloned().unwrap_or(0) == n {
+                    None
+                } else {
+                    Some((*c, n))
+                }
+            })
+            .collect(),
+        players: new.players
+            .iter()
+            .enumerate()
+            .filter(|&(p, state)| old.players.get(p) != Some(state))
+            .map(|(p, state)| {
+                let before = old.players.get(p);
+                PlayerDelta {
+                    player: p,
+                    state: state.clone(),
+                    money_change: match (before.and_then(|b| b.money), state.money) {
+                        (Some(b), Some(a)) if a != b => Some(a as isize - b as isize),
+                        _ => None,
+                    },
+                    share_changes: match (before.and_then(|b| b.shares.as_ref()), &state.shares) {
+                        (Some(b), &Some(ref a)) => share_changes(b, a, &corps),
+                        _ => vec![],
+                    },
+                }
+            })
+            .collect(),
+        phase: changed(&old.phase, &new.phase),
+        remaining_tiles: changed(&old.remaining_tiles, &new.remaining_tiles),
+        last_turn: changed(&old.last_turn, &new.last_turn),
+        finished: changed(&old.finished, &new.finished),
+        bank: changed(&old.bank, &new.bank),
+        market: changed(&old.market, &new.market),
+        end_report: changed(&old.end_report, &new.end_report),
+    }
+}
+
+impl StateDelta {
+    pub fn is_empty(&self) -> bool {
+        *self == StateDelta::default()
+    }
+}
+
+impl PubState {
+    /// Bring the state up to date with a delta made against it.
+    pub fn apply(&mut self, delta: &StateDelta) {
+        for &(loc, tile) in &delta.tiles {
+            self.board.set_tile(&loc, tile);
+        }
+        for &(corp, n) in &delta.shares {
+            self.shares.insert(corp, n);
+        }
+        for player_delta in &delta.players {
+            if player_delta.player < self.players.len() {
+                self.players[player_delta.player] = player_delta.state.clone();
+            } else {
+                self.players.push(player_delta.state.clone());
+            }
+        }
+        if let Some(ref phase) = delta.phase {
+            self.phase = phase.clone();
+        }
+        if let Some(remaining_tiles) = delta.remaining_tiles {
+            self.remaining_tiles = remaining_tiles;
+        }
+        if let Some(last_turn) = delta.last_turn {
+            self.last_turn = last_turn;
+        }
+        if let Some(finished) = delta.finished {
+            self.finished = finished;
+        }
+        if let Some(bank) = delta.bank {
+            self.bank = bank;
+        }
+        if let Some(ref market) = delta.market {
+            self.market = market.clone();
+        }
+        if let Some(ref end_report) = delta.end_report {
+            self.end_report = end_report.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use brdgme_game::Gamer;
+
+    use super::{diff, StateDelta};
+    use crate::board::{Loc, Tile};
+    use crate::corp::Corp;
+    use crate::Game;
+
+    #[test]
+    fn diff_applies_to_old_state() {
+        let players = vec!["mick".to_string(), "steve".to_string()];
+        let mut g: Game = "AA0.
+                           ...1"
+            .into();
+        let before = g.pub_state();
+        g.command(0, "play a3", &players)
+            .expect("expected 'play a3' to work");
+        g.command(0, "buy 2 am", &players)
+            .expect("expected 'buy 2 am' to work");
+        let after = g.pub_state();
+        let delta = diff(&before, &after);
+        assert_eq!(
+            vec![(Loc { row: 0, col: 2 }, Tile::Corp(Corp::American))],
+            delta.tiles
+        );
+        assert_eq!(vec![(Corp::American, 2, 3)], delta.corp_sizes);
+        assert_eq!(1, delta.players.len());
+        assert_eq!(vec![(Corp::American, 2)], delta.players[0].share_changes);
+        let mut applied = before.clone();
+        applied.apply(&delta);
+        assert_eq!(after, applied);
+        assert!(diff(&after, &applied).is_empty());
+    }
+
+    #[test]
+    fn cleared_fields_survive_serialisation() {
+        let mut g: Game = "AA0.
+                           ...1"
+            .into();
+        g.bank = Some(1000);
+        let before = g.pub_state();
+        g.bank = None;
+        let after = g.pub_state();
+        let delta = diff(&before, &after);
+        assert_eq!(Some(None), delta.bank);
+        let json = serde_json::to_string(&delta).expect("expected delta to serialise");
+        let decoded: StateDelta =
+            serde_json::from_str(&json).expect("expected delta to deserialise");
+        assert_eq!(delta, decoded);
+        let mut applied = before.clone();
+        applied.apply(&decoded);
+        assert_eq!(after, applied);
+
+        let unchanged: StateDelta = serde_json::from_str(
+            &serde_json::to_string(&StateDelta::default()).expect("expected delta to serialise"),
+        ).expect("expected delta to deserialise");
+        assert_eq!(None, unchanged.bank);
+        assert_eq!(None, unchanged.market);
+        assert_eq!(None, unchanged.end_report);
+    }
+}
@@ -0,0 +1,159 @@
+use brdgme_game::Log;
+use brdgme_markup::Node as N;
+
+use crate::board::{Loc, TileEffect};
+use crate::errors::{AcquireError, Action};
+use crate::i18n::{Locale, Msg};
+use crate::options::{DeadTileRule, UnplayableHandRule};
+use crate::render::comma_list;
+use crate::Game;
+
+impl Game {
+    /// Tiles in a player's hand which can never be played as they would merge safe corporations.
+    pub fn dead_tiles(&self, player: usize) -> Vec<Loc> {
+        self.players
+            .get(player)
+            .map(|p| {
+                p.tiles
+                    .iter()
+                    .filter(|loc| self.board.loc_neighbours_multiple_safe_corps(loc))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_else(|| vec![])
+    }
+
+    pub fn can_discard(&self, player: usize) -> bool {
+        let allowed = match self.options.dead_tile_rule {
+            DeadTileRule::AutoDiscard => false,
+            DeadTileRule::ExchangeOne => self.turn_discards == 0,
+            DeadTileRule::PlayerChoice => true,
+        };
+        allowed && self.can_play(player) && !self.dead_tiles(player).is_empty()
+    }
+
+    pub fn handle_discard_command(
+        &mut self,
+        player: usize,
+        loc: &Loc,
+    ) -> Result<(Vec<Log>, bool), AcquireError> {
+        self.ensure_not_finished()?;
+        self.ensure_player_turn(player)?;
+        if !self.can_play(player) || self.options.dead_tile_rule == DeadTileRule::AutoDiscard {
+            return Err(AcquireError::WrongPhase(Action::Discard));
+        }
+        if self.options.dead_tile_rule == DeadTileRule::ExchangeOne && self.turn_discards > 0 {
+            return Err(AcquireError::DiscardLimit);
+        }
+        let pos = match self.players[player].tiles.iter().position(|l| l == loc) {
+            Some(p) => p,
+            None => return Err(AcquireError::TileNotInHand(*loc)),
+        };
+        if !self.board.loc_neighbours_multiple_safe_corps(loc) {
+            return Err(AcquireError::TileNotDead(*loc));
+        }
+        self.players[player].tiles.swap_remove(pos);
+        self.board.set_discarded(&[*loc]);
+        self.turn_discards += 1;
+        let mut logs = self.public_logs(|l| {
+            l.fill(Msg::DiscardedDead, vec![N::Player(player), loc.render()])
+        });
+        let mut can_undo = true;
+        if self.options.dead_tile_rule == DeadTileRule::ExchangeOne && !self.draw_tiles.is_empty()
+        {
+            let drawn = self.draw_tiles.remove(0);
+            self.players[player].tiles.push(drawn);
+            logs.extend(self.drawn_tiles_logs(player, &[drawn]));
+            can_undo = false;
+        }
+        logs.extend(self.check_playable_hand(player)?);
+        Ok((logs, can_undo))
+    }
+
+    /// Handle a player being unable to place a tile, unless they can still discard dead tiles.
+    pub(crate) fn check_playable_hand(&mut self, player: usize) -> Result<Vec<Log>, AcquireError> {
+        if self.finished
+            || self.players[player]
+                .tiles
+                .iter()
+                .any(|loc| self.board.assert_loc_playable(loc).is_ok())
+            || self.can_discard(player)
+        {
+            return Ok(vec![]);
+        }
+        match self.options.unplayable_hand_rule {
+            UnplayableHandRule::Redraw => {
+                let (mut logs, has_ended) = self.redraw_hand(player)?;
+                if !has_ended {
+                    logs.extend(self.start_turn(player)?);
+                }
+                Ok(logs)
+            }
+            UnplayableHandRule::Announce => {
+                let logs = self.public_logs(|l| {
+                    l.fill(
+                        Msg::SkipPlacement,
+                        vec![
+                            N::Player(player),
+                            self.unplayable_tile_list(l, &self.players[player].tiles),
+                        ],
+                    )
+                });
+                self.buy_phase(player);
+                Ok(logs)
+            }
+        }
+    }
+
+    /// A list of tiles, each marked with whether it can never be played or is only unplayable
+    /// for now.
+    pub(crate) fn unplayable_tile_list(&self, locale: Locale, tiles: &[Loc]) -> N {
+        comma_list(
+            tiles
+                .iter()
+                .map(|loc| match self.board.tile_effect(loc) {
+                    TileEffect::MergesSafeCorps(_) => {
+                        N::Group(locale.fill(Msg::TileDead, vec![loc.render()]))
+                    }
+                    TileEffect::NoCorpAvailable => {
+                        N::Group(locale.fill(Msg::TileBlocked, vec![loc.render()]))
+                    }
+                    _ => loc.render(),
+                })
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use brdgme_game::Gamer;
+
+    use crate::board::{Loc, Tile};
+    use crate::errors::AcquireError;
+    use crate::options::DeadTileRule;
+    use crate::{Game, Phase};
+
+    #[test]
+    fn exchange_one_dead_tile_per_turn() {
+        let players = vec!["mick".to_string(), "steve".to_string()];
+        let mut g: Game = "AAAAAAAAAAA0
+                           .FFFFFFFFFFF
+                           ............
+                           0...........
+                           .....1......"
+            .into();
+        g.options.dead_tile_rule = DeadTileRule::ExchangeOne;
+        g.draw_tiles = (0..6).map(|col| Loc { row: 5, col }).collect();
+        assert_eq!(vec![Loc { row: 0, col: 11 }], g.dead_tiles(0));
+        g.command(0, "discard a12", &players)
+            .expect("expected discarding a dead tile to work");
+        assert_eq!(Tile::Discarded, g.board.get_tile(Loc { row: 0, col: 11 }));
+        assert!(g.players[0].tiles.contains(&Loc { row: 5, col: 0 }));
+        assert_eq!(Phase::Play(0), g.phase);
+        assert_eq!(
+            Some(AcquireError::DiscardLimit),
+            g.handle_discard_command(0, &Loc { row: 3, col: 0 }).err()
+        );
+    }
+}
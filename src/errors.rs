@@ -0,0 +1,205 @@
+use serde_derive::{Serialize, Deserialize};
+
+use brdgme_game::errors::GameError;
+
+use std::error::Error;
+use std::fmt;
+
+use crate::board::Loc;
+use crate::corp::Corp;
+use crate::i18n::{Locale, Msg};
+use crate::roster::Roster;
+use crate::undo::UndoBarrier;
+use crate::Game;
+
+/// Actions which are only possible during certain phases of a turn.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Action {
+    Play,
+    Discard,
+    Found,
+    Buy,
+    Done,
+    Merge,
+    SellOrTrade,
+    End,
+    Borrow,
+    Repay,
+    Bid,
+}
+
+/// Rule violations and other failures when acting on the game. These carry enough data for
+/// clients and bots to react without parsing messages, and convert into `GameError` for the
+/// platform.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum AcquireError {
+    Finished,
+    NotYourTurn,
+    WrongPhase(Action),
+    TileNotInHand(Loc),
+    TileNotDead(Loc),
+    DiscardLimit,
+    WouldMergeSafeCorps(Vec<Corp>),
+    NoCorpAvailable,
+    CorpOnBoard(Corp),
+    CorpNotOnBoard(Corp),
+    ZeroAmount,
+    BuyLimit { remaining: usize },
+    BankOut { corp: Corp, left: usize },
+    InsufficientFunds { cost: usize, cash: usize },
+    InsufficientShares { corp: Corp, held: usize },
+    InvalidTradeAmount { n: usize },
+    LoanLimit { limit: usize },
+    BankCantLend { balance: usize },
+    BidTooLow { min: usize },
+    SameCorpMerge(Corp),
+    InvalidMergeFrom(Corp),
+    InvalidMergeInto(Corp),
+    EndDuringOtherTurn,
+    AlreadyResigned,
+    NoSuchPlayer(usize),
+    UndoOtherPlayer,
+    NothingToUndo,
+    UndoBlocked(UndoBarrier),
+    NoBasePrice(Corp),
+    BracketsOutOfOrder,
+    InvalidRoster,
+    BoardTooSmall { needed: usize },
+    BankTooSmall { needed: usize },
+    InvalidTeams { players: usize },
+    Internal(String),
+}
+
+impl AcquireError {
+    /// The error message in the given locale, naming corporations as the roster does.
+    pub fn localise(&self, locale: Locale, roster: &Roster) -> String {
+        let (msg, args) = match *self {
+            AcquireError::Finished => (Msg::ErrFinished, vec![]),
+            AcquireError::NotYourTurn => (Msg::ErrNotYourTurn, vec![]),
+            AcquireError::WrongPhase(action) => (
+                match action {
+                    Action::Play => Msg::ErrCantPlay,
+                    Action::Discard => Msg::ErrCantDiscard,
+                    Action::Found => Msg::ErrCantFound,
+                    Action::Buy => Msg::ErrCantBuy,
+                    Action::Done => Msg::ErrCantDone,
+                    Action::Merge => Msg::ErrCantMerge,
+                    Action::SellOrTrade => Msg::ErrCantSellOrTrade,
+                    Action::End => Msg::ErrCantEnd,
+                    Action::Borrow => Msg::ErrCantBorrow,
+                    Action::Repay => Msg::ErrCantRepay,
+                    Action::Bid => Msg::ErrCantBid,
+                },
+                vec![],
+            ),
+            AcquireError::TileNotInHand(loc) => (Msg::ErrTileNotInHand, vec![loc.to_string()]),
+            AcquireError::TileNotDead(loc) => (Msg::ErrTileNotDead, vec![loc.to_string()]),
+            AcquireError::DiscardLimit => (Msg::ErrDiscardLimit, vec![]),
+            AcquireError::WouldMergeSafeCorps(ref corps) => (
+                Msg::ErrWouldMergeSafeCorps,
+                vec![
+                    corps
+                        .iter()
+                        .map(|c| roster.name(c))
+                        .collect::<Vec<String>>()
+                        .join(", "),
+                ],
+            ),
+            AcquireError::NoCorpAvailable => (Msg::ErrNoCorpAvailable, vec![]),
+            AcquireError::CorpOnBoard(corp) => (Msg::ErrCorpOnBoard, vec![roster.name(&corp)]),
+            AcquireError::CorpNotOnBoard(corp) => {
+                (Msg::ErrCorpNotOnBoard, vec![roster.name(&corp)])
+            }
+            AcquireError::ZeroAmount => (Msg::ErrZeroAmount, vec![]),
+            AcquireError::BuyLimit { remaining } => (Msg::ErrBuyLimit, vec![remaining.to_string()]),
+            AcquireError::BankOut { corp, left } => {
+                (Msg::ErrBankOut, vec![roster.name(&corp), left.to_string()])
+            }
+            AcquireError::InsufficientFunds { cost, cash } => (
+                Msg::ErrInsufficientFunds,
+                vec![cost.to_string(), cash.to_string()],
+            ),
+            AcquireError::InsufficientShares { corp, held } => (
+                Msg::ErrInsufficientShares,
+                vec![held.to_string(), roster.name(&corp)],
+            ),
+            AcquireError::InvalidTradeAmount { n } => {
+                (Msg::ErrInvalidTradeAmount, vec![n.to_string()])
+            }
+            AcquireError::LoanLimit { limit } => (Msg::ErrLoanLimit, vec![limit.to_string()]),
+            AcquireError::BankCantLend { balance } => {
+                (Msg::ErrBankCantLend, vec![balance.to_string()])
+            }
+            AcquireError::BidTooLow { min } => (Msg::ErrBidTooLow, vec![min.to_string()]),
+            AcquireError::SameCorpMerge(_) => (Msg::ErrSameCorpMerge, vec![]),
+            AcquireError::InvalidMergeFrom(corp) => {
+                (Msg::ErrInvalidMergeFrom, vec![roster.name(&corp)])
+            }
+            AcquireError::InvalidMergeInto(corp) => {
+                (Msg::ErrInvalidMergeInto, vec![roster.name(&corp)])
+            }
+            AcquireError::EndDuringOtherTurn => (Msg::ErrEndDuringOtherTurn, vec![]),
+            AcquireError::AlreadyResigned => (Msg::ErrAlreadyResigned, vec![]),
+            AcquireError::NoSuchPlayer(player) => (Msg::ErrNoSuchPlayer, vec![player.to_string()]),
+            AcquireError::UndoOtherPlayer => (Msg::ErrUndoOtherPlayer, vec![]),
+            AcquireError::NothingToUndo => (Msg::ErrNothingToUndo, vec![]),
+            AcquireError::UndoBlocked(barrier) => (barrier.msg(), vec![]),
+            AcquireError::NoBasePrice(corp) => (Msg::ErrNoBasePrice, vec![roster.name(&corp)]),
+            AcquireError::BracketsOutOfOrder => (Msg::ErrBracketsOutOfOrder, vec![]),
+            AcquireError::InvalidRoster => (Msg::ErrInvalidRoster, vec![]),
+            AcquireError::BoardTooSmall { needed } => {
+                (Msg::ErrBoardTooSmall, vec![needed.to_string()])
+            }
+            AcquireError::BankTooSmall { needed } => {
+                (Msg::ErrBankTooSmall, vec![needed.to_string()])
+            }
+            AcquireError::InvalidTeams { players } => {
+                (Msg::ErrInvalidTeams, vec![players.to_string()])
+            }
+            AcquireError::Internal(ref message) => return message.clone(),
+        };
+        locale.format(msg, &args)
+    }
+
+    /// Convert into a `GameError` with the message in the given locale.
+    pub fn into_game_error(self, locale: Locale, roster: &Roster) -> GameError {
+        match self {
+            AcquireError::Internal(message) => GameError::Internal { message },
+            err => GameError::InvalidInput {
+                message: err.localise(locale, roster),
+            },
+        }
+    }
+}
+
+impl fmt::Display for AcquireError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.localise(Locale::En, &Roster::default()))
+    }
+}
+
+impl Error for AcquireError {}
+
+impl From<AcquireError> for GameError {
+    fn from(err: AcquireError) -> Self {
+        err.into_game_error(Locale::En, &Roster::default())
+    }
+}
+
+impl Game {
+    pub(crate) fn ensure_not_finished(&self) -> Result<(), AcquireError> {
+        if self.finished {
+            Err(AcquireError::Finished)
+        } else {
+            Ok(())
+        }
+    }
+
+    pub(crate) fn ensure_player_turn(&self, player: usize) -> Result<(), AcquireError> {
+        if self.phase.acting().contains(&player) {
+            Ok(())
+        } else {
+            Err(AcquireError::NotYourTurn)
+        }
+    }
+}
@@ -0,0 +1,260 @@
+use serde_derive::{Serialize, Deserialize};
+
+use brdgme_game::Log;
+use brdgme_markup::{row_pad, Align as A, Node as N, Row};
+
+use std::collections::HashMap;
+use std::mem;
+
+use crate::board::Loc;
+use crate::corp::Corp;
+use crate::i18n::{Locale, Msg};
+use crate::render::{comma_list, ROW_PAD};
+use crate::visibility::redacted;
+use crate::Game;
+
+/// A player's cash and holdings at the end of a turn.
+#[derive(Default, PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct Holdings {
+    pub money: usize,
+    pub shares: HashMap<Corp, usize>,
+    pub debt: usize,
+    /// Cash plus shares at the prices of the time, less debt.
+    pub net_worth: usize,
+}
+
+/// What happened during a completed turn.
+#[derive(Default, PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct TurnRecord {
+    pub player: usize,
+    /// The tile played, if the player had one to play.
+    pub tile: Option<Loc>,
+    pub founded: Vec<Corp>,
+    /// Mergers as the defunct corporation and the corporation it merged into.
+    pub merged: Vec<(Corp, Corp)>,
+    pub bought: Vec<(Corp, usize)>,
+    /// Every player's holdings once the turn was over.
+    pub players: Vec<Holdings>,
+}
+
+static HISTORY_TABLE_HEADER: &'static [Msg] = &[
+    Msg::HeaderTurn,
+    Msg::HeaderPlayer,
+    Msg::HeaderTile,
+    Msg::HeaderFounded,
+    Msg::HeaderMerged,
+    Msg::HeaderBought,
+];
+
+fn money(amount: usize) -> N {
+    N::text(format!("${}", amount))
+}
+
+impl Game {
+    /// Every completed turn, oldest first.
+    pub fn history(&self) -> &[TurnRecord] {
+        &self.turn_history
+    }
+
+    pub(crate) fn record_buy(&mut self, corp: Corp, n: usize) {
+        let bought = &mut self.current_turn.bought;
+        match bought.iter().position(|&(c, _)| c == corp) {
+            Some(i) => bought[i].1 += n,
+            None => bought.push((corp, n)),
+        }
+    }
+
+    fn holdings(&self, player: usize) -> Holdings {
+        let state = &self.players[player];
+        let share_value: usize = state
+            .shares
+            .iter()
+            .filter(|&(c, _)| self.board.corp_size(c) > 0)
+            .map(|(c, n)| n * self.share_price(c))
+            .sum();
+        let debt = state.debt();
+        Holdings {
+            money: state.money,
+            shares: state
+                .shares
+                .iter()
+                .filter(|&(_, n)| *n > 0)
+                .map(|(c, n)| (*c, *n))
+                .collect(),
+            debt,
+            net_worth: (state.money + share_value).saturating_sub(debt),
+        }
+    }
+
+    /// Close off the turn in progress and add it to the history.
+    pub(crate) fn record_turn(&mut self, player: usize) {
+        let players = (0..self.players.len()).map(|p| self.holdings(p)).collect();
+        let mut turn = mem::replace(&mut self.current_turn, TurnRecord::default());
+        turn.player = player;
+        turn.players = players;
+        self.turn_history.push(turn);
+    }
+
+    /// A row for each completed turn showing what the player did. Numbers of shares bought are
+    /// only shown to the buyer and their partners when shares are hidden.
+    pub fn render_history(&self, viewer: usize, locale: Locale) -> N {
+        let count_shown = |p: usize| {
+            self.shares_visible() || (viewer < self.players.len() && self.is_teammate(viewer, p))
+        };
+        let mut rows: Vec<Row> = vec![
+            row_pad(
+                &HISTORY_TABLE_HEADER
+                    .iter()
+                    .map(|h| (A::Left, vec![N::Bold(vec![N::text(locale.template(*h))])]))
+                    .collect::<Row>(),
+                ROW_PAD,
+            ),
+        ];
+        rows.extend(self.turn_history.iter().enumerate().map(|(i, turn)| {
+            row_pad(
+                &[
+                    (A::Left, vec![N::text(format!("{}", i + 1))]),
+                    (A::Left, vec![N::Player(turn.player)]),
+                    (
+                        A::Left,
+                        vec![turn.tile.map(|t| t.render()).unwrap_or_else(|| N::text("-"))],
+                    ),
+                    (
+                        A::Left,
+                        vec![comma_list(
+                            turn.founded.iter().map(|c| self.render_corp(c)).collect(),
+                        )],
+                    ),
+                    (
+                        A::Left,
+                        vec![comma_list(
+                            turn.merged
+                                .iter()
+                                .map(|&(from, into)| {
+                                    N::Group(locale.fill(
+                                        Msg::HistoryMerged,
+                                        vec![self.render_corp(&from), self.render_corp(&into)],
+                                    ))
+                                })
+                                .collect(),
+                        )],
+                    ),
+                    (
+                        A::Left,
+                        vec![comma_list(
+                            turn.bought
+                                .iter()
+                                .map(|&(c, n)| {
+                                    N::Group(vec![
+                                        if count_shown(turn.player) {
+                                            N::text(format!("{}", n))
+                                        } else {
+                                            redacted()
+                                        },
+                                        N::text(" "),
+                                        self.render_corp(&c),
+                                    ])
+                                })
+                                .collect(),
+                        )],
+                    ),
+                ],
+                ROW_PAD,
+            )
+        }));
+        N::Table(rows)
+    }
+
+    /// A row for each completed turn showing every player's cash and net worth afterwards.
+    /// Values hidden by the visibility options are only shown to the player and their partners.
+    pub fn render_timeline(&self, viewer: usize, locale: Locale) -> N {
+        let partner = |p: usize| viewer < self.players.len() && self.is_teammate(viewer, p);
+        let cash_shown = |p: usize| self.money_visible() || partner(p);
+        let worth_shown = |p: usize| (self.money_visible() && self.shares_visible()) || partner(p);
+        let mut header: Row = vec![
+            (
+                A::Left,
+                vec![N::Bold(vec![N::text(locale.template(Msg::HeaderTurn))])],
+            ),
+        ];
+        header.extend((0..self.players.len()).map(|p| (A::Left, vec![N::Player(p)])));
+        let mut rows: Vec<Row> = vec![row_pad(&header, ROW_PAD)];
+        for (i, turn) in self.turn_history.iter().enumerate() {
+            let mut row: Row = vec![(A::Left, vec![N::text(format!("{}", i + 1))])];
+            row.extend(turn.players.iter().enumerate().map(|(p, holdings)| {
+                (
+                    A::Left,
+                    vec![
+                        if cash_shown(p) { money(holdings.money) } else { redacted() },
+                        N::text(" / "),
+                        if worth_shown(p) { money(holdings.net_worth) } else { redacted() },
+                    ],
+                )
+            }));
+            rows.push(row_pad(&row, ROW_PAD));
+        }
+        N::Table(rows)
+    }
+
+    pub fn history_query(&self, player: usize) -> Vec<Log> {
+        let l = self.locale(player);
+        vec![Log::private(
+            if self.turn_history.is_empty() {
+                vec![N::text(l.template(Msg::HistoryEmpty))]
+            } else {
+                vec![self.render_history(player, l)]
+            },
+            vec![player],
+        )]
+    }
+
+    pub fn timeline_query(&self, player: usize) -> Vec<Log> {
+        let l = self.locale(player);
+        vec![Log::private(
+            if self.turn_history.is_empty() {
+                vec![N::text(l.template(Msg::HistoryEmpty))]
+            } else {
+                vec![
+                    N::text(l.template(Msg::TimelineKey)),
+                    N::text("\n"),
+                    self.render_timeline(player, l),
+                ]
+            },
+            vec![player],
+        )]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use brdgme_game::Gamer;
+
+    use crate::board::Loc;
+    use crate::corp::Corp;
+    use crate::{Game, STARTING_MONEY};
+
+    #[test]
+    fn history_records_turns() {
+        let players = vec!["mick".to_string(), "steve".to_string()];
+        let mut g: Game = "AA0.
+                           ...1"
+            .into();
+        g.command(0, "play a3", &players)
+            .expect("expected 'play a3' to work");
+        g.command(0, "buy 2 am", &players)
+            .expect("expected 'buy 2 am' to work");
+        g.command(0, "buy 1 am", &players)
+            .expect("expected 'buy 1 am' to work");
+        g.command(0, "done", &players)
+            .expect("expected 'done' to work");
+        assert_eq!(1, g.history().len());
+        let turn = &g.history()[0];
+        assert_eq!(0, turn.player);
+        assert_eq!(Some(Loc { row: 0, col: 2 }), turn.tile);
+        assert_eq!(vec![(Corp::American, 3)], turn.bought);
+        let price = g.share_price(&Corp::American);
+        assert_eq!(STARTING_MONEY - 3 * price, turn.players[0].money);
+        assert_eq!(STARTING_MONEY, turn.players[0].net_worth);
+        assert_eq!(STARTING_MONEY, turn.players[1].net_worth);
+    }
+}
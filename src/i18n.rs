@@ -0,0 +1,931 @@
+use serde_derive::{Serialize, Deserialize};
+
+use brdgme_game::Log;
+use brdgme_markup::Node as N;
+
+use std::fmt;
+
+use crate::errors::AcquireError;
+use crate::Game;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Locale {
+    En,
+    De,
+}
+
+pub static LOCALES: [Locale; 2] = [Locale::En, Locale::De];
+
+impl Default for Locale {
+    fn default() -> Self {
+        Locale::En
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match *self {
+                Locale::En => "English",
+                Locale::De => "Deutsch",
+            }
+        )
+    }
+}
+
+/// Entries in the message catalogue. Templates refer to their arguments as `{0}`, `{1}` and so
+/// on, so each locale can order them as its grammar requires.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Msg {
+    TwoPlayerRule,
+    WillStart,
+    Played,
+    Discarded,
+    YouDrew,
+    IncreasedInSize,
+    Founded,
+    Bought,
+    PayingBonuses,
+    RedrawHand,
+    DiscardedDead,
+    TileDead,
+    TileBlocked,
+    SkipPlacement,
+    MergingInto,
+    DummyRolled,
+    MajorBonus,
+    MinorBonus,
+    NoShareholders,
+    DummyPlayer,
+    Sold,
+    Borrowed,
+    Repaid,
+    LoanDue,
+    LoansSettled,
+    AuctionStarted,
+    BidPlaced,
+    PassedBid,
+    SealedBidMade,
+    SealedBids,
+    BidPassed,
+    AuctionWon,
+    AuctionNoBids,
+    AuctionWaiting,
+    AuctionHighBid,
+    AuctionOpening,
+    MarketMoved,
+    Traded,
+    Kept,
+    TriggeredEnd,
+    ResignedBot,
+    ResignedLiquidate,
+    AllResigned,
+    ReturnedToBank,
+    TimeoutPlay,
+    TimeoutFound,
+    TimeoutDone,
+    TimeoutMerge,
+    TimeoutKeep,
+    TimeoutOther,
+    Undid,
+    LanguageChanged,
+    PlayerDrew,
+    FinalCash,
+    HoldingsHidden,
+    TiedBrokenBy,
+    TiedShared,
+    TiebreakMostShares,
+    TiebreakMostMajorBonuses,
+    TiebreakTurnOrder,
+    HeaderCashBefore,
+    HeaderSale,
+    HeaderLoans,
+    HeaderFinalCash,
+    HeaderPlacing,
+    HeaderBehind,
+    HeaderTurn,
+    HeaderTile,
+    HeaderFounded,
+    HeaderMerged,
+    HeaderBought,
+    HistoryMerged,
+    HistoryEmpty,
+    TimelineKey,
+    InfoNotOnBoard,
+    InfoSafe,
+    InfoNotSafe,
+    InfoNextBracket,
+    InfoMaxValue,
+    InfoBankShares,
+    InfoNoHolders,
+    InfoHolders,
+    TileOccupied,
+    TileSafeMerge,
+    TileNoCorp,
+    TileUnincorporated,
+    TileFound,
+    TileGrow,
+    TileMergeChoice,
+    TileMerge,
+    MajorityNobody,
+    MajorityOnly,
+    MajorityTied,
+    MajorityLeads,
+    DeckUnseen,
+    DeckFoundChance,
+    HeaderCorporation,
+    HeaderSize,
+    HeaderValue,
+    HeaderShares,
+    HeaderMinor,
+    HeaderMajor,
+    HeaderMajorHolder,
+    HeaderMinorHolder,
+    HeaderPlayer,
+    HeaderCash,
+    HeaderTiles,
+    HeaderWorth,
+    HeaderDebt,
+    HeaderTeam,
+    TeamName,
+    SharesLeft,
+    DrawTilesRemaining,
+    SeatBot,
+    SeatResigned,
+    Tied,
+    EndTriggered,
+    EndFinished,
+    EndCanTrigger,
+    EndReasonLargest,
+    EndReasonAllSafe,
+    AutoEnded,
+    AdviseEnd,
+    ProjectedStandings,
+    ProjectedCash,
+    BankShortfall,
+    BankBalance,
+    NoCorpsFounded,
+    LargestCorp,
+    DocPlay,
+    DocDiscard,
+    DocFound,
+    DocFoundCorp,
+    DocBuy,
+    DocBuyAmount,
+    DocBuyCorp,
+    DocSell,
+    DocSellAmount,
+    DocTrade,
+    DocTradeAmount,
+    DocMerge,
+    DocMergeFrom,
+    DocMergeInto,
+    DocEnd,
+    DocDone,
+    DocKeep,
+    DocBorrow,
+    DocRepay,
+    DocBid,
+    DocBidAmount,
+    DocPass,
+    DocResign,
+    DocUndo,
+    DocInfo,
+    DocQueryCorp,
+    DocTileQuery,
+    DocMajority,
+    DocDeck,
+    DocHistory,
+    DocTimeline,
+    DocLanguage,
+    DocLanguageLocale,
+    ErrFinished,
+    ErrNotYourTurn,
+    ErrCantPlay,
+    ErrCantDiscard,
+    ErrCantFound,
+    ErrCantBuy,
+    ErrCantDone,
+    ErrCantMerge,
+    ErrCantSellOrTrade,
+    ErrCantEnd,
+    ErrCantBorrow,
+    ErrCantRepay,
+    ErrCantBid,
+    ErrTileNotInHand,
+    ErrTileNotDead,
+    ErrDiscardLimit,
+    ErrWouldMergeSafeCorps,
+    ErrNoCorpAvailable,
+    ErrCorpOnBoard,
+    ErrCorpNotOnBoard,
+    ErrZeroAmount,
+    ErrBuyLimit,
+    ErrBankOut,
+    ErrInsufficientFunds,
+    ErrInsufficientShares,
+    ErrInvalidTradeAmount,
+    ErrLoanLimit,
+    ErrBankCantLend,
+    ErrBidTooLow,
+    ErrSameCorpMerge,
+    ErrInvalidMergeFrom,
+    ErrInvalidMergeInto,
+    ErrEndDuringOtherTurn,
+    ErrAlreadyResigned,
+    ErrNoSuchPlayer,
+    ErrUndoOtherPlayer,
+    ErrNothingToUndo,
+    ErrUndoTilesDrawn,
+    ErrUndoDiceRolled,
+    ErrUndoOtherDecision,
+    ErrUndoTimedOut,
+    ErrUndoIrreversible,
+    ErrNoBasePrice,
+    ErrBracketsOutOfOrder,
+    ErrInvalidRoster,
+    ErrBoardTooSmall,
+    ErrBankTooSmall,
+    ErrInvalidTeams,
+}
+
+fn en(msg: Msg) -> &'static str {
+    match msg {
+        Msg::TwoPlayerRule => "\
+2 player special rule: a dummy player is added for shareholder bonuses. A dice (D6) is rolled to \
+determine the dummy player's shares. The money for the dummy player is not tracked and it is not \
+able to win the game.",
+        Msg::WillStart => "{0} will start the game",
+        Msg::Played => "{0} played {1}",
+        Msg::Discarded => "{0} discarded {1}",
+        Msg::YouDrew => "You drew {0}",
+        Msg::IncreasedInSize => "{0} increased in size to {1}",
+        Msg::Founded => "{0} founded {1}",
+        Msg::Bought => "{0} bought {1} {2} for {3}",
+        Msg::PayingBonuses => "Paying shareholder bonuses for {0}",
+        Msg::RedrawHand => "{0} has no playable tiles and will draw a new hand, discarded {1}",
+        Msg::DiscardedDead => "{0} discarded {1}, which can never be played",
+        Msg::TileDead => "{0} (can never be played)",
+        Msg::TileBlocked => "{0} (can't be played until a corporation is available)",
+        Msg::SkipPlacement => "{0} has no playable tiles and won't place a tile this turn: {1}",
+        Msg::MergingInto => "{0} is merging into {1}",
+        Msg::DummyRolled => "The dummy player rolled {0}",
+        Msg::MajorBonus => "Major bonus of {0} to {1}",
+        Msg::MinorBonus => "Minor bonus of {0} to {1}",
+        Msg::NoShareholders => "Nobody holds shares in {0}, so no bonuses are paid",
+        Msg::DummyPlayer => "dummy player",
+        Msg::Sold => "{0} sold {1} {2} for {3}",
+        Msg::Borrowed => "{0} borrowed {1} and owes {2}",
+        Msg::Repaid => "{0} repaid a loan of {1}",
+        Msg::LoanDue => "A loan taken by {0} is due, {1} was repaid and {2} is still owed",
+        Msg::LoansSettled => "{0} repaid {1} of {2} owed on loans",
+        Msg::AuctionStarted => "{0} {1} shares are up for auction",
+        Msg::BidPlaced => "{0} bid {1}",
+        Msg::PassedBid => "{0} passed",
+        Msg::SealedBidMade => "{0} made a sealed bid",
+        Msg::SealedBids => "The sealed bids were {0}",
+        Msg::BidPassed => "passed",
+        Msg::AuctionWon => "{0} won {1} {2} shares for {3}",
+        Msg::AuctionNoBids => "Nobody bid for the {0} shares",
+        Msg::AuctionWaiting => "Auction for {0}, waiting for bids from {1}",
+        Msg::AuctionHighBid => "Auction for {0}, the high bid is {1} by {2}",
+        Msg::AuctionOpening => "Auction for {0}, no bids yet",
+        Msg::MarketMoved => "{0} shares moved from {1} to {2}",
+        Msg::Traded => "{0} traded {1} {2} for {3} {4}",
+        Msg::Kept => "{0} kept {1} {2}",
+        Msg::TriggeredEnd => "{0} triggered the end of the game at the end of their turn as {1}",
+        Msg::ResignedBot => "{0} resigned, a bot will play out their turns",
+        Msg::ResignedLiquidate => {
+            "{0} resigned, their shares will be sold and their tiles discarded"
+        }
+        Msg::AllResigned => "All players have resigned, ending the game",
+        Msg::ReturnedToBank => "{0} returned {1} {2} to the bank",
+        Msg::TimeoutPlay => "{0} ran out of time, automatically playing {1}",
+        Msg::TimeoutFound => "{0} ran out of time, automatically founding {1}",
+        Msg::TimeoutDone => "{0} ran out of time, automatically finishing buying shares",
+        Msg::TimeoutMerge => "{0} ran out of time, automatically merging {1} into {2}",
+        Msg::TimeoutKeep => "{0} ran out of time, automatically keeping their shares",
+        Msg::TimeoutOther => "{0} ran out of time, automatically taking their turn",
+        Msg::Undid => "{0} undid their last action",
+        Msg::LanguageChanged => "The game will now be shown in {0}",
+        Msg::PlayerDrew => "{0} drew {1}",
+        Msg::FinalCash => "Final cash: {0}",
+        Msg::HoldingsHidden => "Shareholdings are hidden in this game",
+        Msg::TiedBrokenBy => "{0} tied with {1}, placed by {2}",
+        Msg::TiedShared => "{0} tied with {1} and share their placing",
+        Msg::TiebreakMostShares => "most shares held at the end",
+        Msg::TiebreakMostMajorBonuses => "most major bonuses earned",
+        Msg::TiebreakTurnOrder => "earliest in turn order",
+        Msg::HeaderCashBefore => "Cash before",
+        Msg::HeaderSale => "Sale",
+        Msg::HeaderLoans => "Loans",
+        Msg::HeaderFinalCash => "Final cash",
+        Msg::HeaderPlacing => "Placing",
+        Msg::HeaderBehind => "Behind",
+        Msg::HeaderTurn => "Turn",
+        Msg::HeaderTile => "Tile",
+        Msg::HeaderFounded => "Founded",
+        Msg::HeaderMerged => "Merged",
+        Msg::HeaderBought => "Bought",
+        Msg::HistoryMerged => "{0} into {1}",
+        Msg::HistoryEmpty => "No turns have been completed yet",
+        Msg::TimelineKey => "Cash / net worth after each turn",
+        Msg::InfoNotOnBoard => "{0} is not on the board, shares will be worth {1} when founded",
+        Msg::InfoSafe => "{0} has size {1} and shares are worth {2}, it is safe",
+        Msg::InfoNotSafe => "{0} has size {1} and shares are worth {2}, it is not safe",
+        Msg::InfoNextBracket => "Shares will be worth {0} at size {1}",
+        Msg::InfoMaxValue => "Shares are at their maximum value",
+        Msg::InfoBankShares => "{0} shares left in the bank",
+        Msg::InfoNoHolders => "Nobody holds shares",
+        Msg::InfoHolders => "Shareholders: {0}",
+        Msg::TileOccupied => "Playing {0} isn't possible, it is already on the board",
+        Msg::TileSafeMerge => "Playing {0} isn't possible, it would merge safe corporations {1}",
+        Msg::TileNoCorp => {
+            "Playing {0} isn't possible, it would found a corporation but none are available"
+        }
+        Msg::TileUnincorporated => "Playing {0} would leave it unincorporated",
+        Msg::TileFound => "Playing {0} would found a new corporation",
+        Msg::TileGrow => "Playing {0} would increase the size of {1} to {2}",
+        Msg::TileMergeChoice => {
+            "Playing {0} would merge {1}, with the player choosing which survives"
+        }
+        Msg::TileMerge => "Playing {0} would merge {1} into {2}",
+        Msg::MajorityNobody => "Nobody holds shares in {0}",
+        Msg::MajorityOnly => "{0} is the only shareholder in {1} with {2}",
+        Msg::MajorityTied => "{0} are tied for the lead in {1} with {2}",
+        Msg::MajorityLeads => "{0} leads {1} with {2}, ahead of {3} by {4}",
+        Msg::DeckUnseen => "{0} tiles unseen: {1}",
+        Msg::DeckFoundChance => "Chance of drawing a tile which founds a corporation: {0}",
+        Msg::HeaderCorporation => "Corporation",
+        Msg::HeaderSize => "Size",
+        Msg::HeaderValue => "Value",
+        Msg::HeaderShares => "Shares",
+        Msg::HeaderMinor => "Minor",
+        Msg::HeaderMajor => "Major",
+        Msg::HeaderMajorHolder => "Major holder",
+        Msg::HeaderMinorHolder => "Minor holder",
+        Msg::HeaderPlayer => "Player",
+        Msg::HeaderCash => "Cash",
+        Msg::HeaderTiles => "Tiles",
+        Msg::HeaderWorth => "Worth",
+        Msg::HeaderDebt => "Debt",
+        Msg::HeaderTeam => "Team",
+        Msg::TeamName => "Team {0}",
+        Msg::SharesLeft => "{0} left",
+        Msg::DrawTilesRemaining => "Draw tiles remaining: {0}",
+        Msg::SeatBot => " (bot)",
+        Msg::SeatResigned => " (resigned)",
+        Msg::Tied => " (tied)",
+        Msg::EndTriggered => "The game will end at the end of this turn",
+        Msg::EndFinished => "The game has ended",
+        Msg::EndCanTrigger => "The end of the game can be triggered",
+        Msg::EndReasonLargest => "{0} has reached {1} tiles",
+        Msg::EndReasonAllSafe => "every corporation on the board is safe",
+        Msg::AutoEnded => "The game ends automatically as {0}",
+        Msg::AdviseEnd => "You can end the game with end as {0}",
+        Msg::ProjectedStandings => "If the game ended now: {0}",
+        Msg::ProjectedCash => "If the game ended now you would have {0}",
+        Msg::BankShortfall => "The bank has run short and could only pay {0} of the {1} owed",
+        Msg::BankBalance => "Money in the bank: {0}",
+        Msg::NoCorpsFounded => "No corporations have been founded yet",
+        Msg::LargestCorp => "Largest corporation is {0} of {1}, {2} unsafe remaining",
+        Msg::DocPlay => "play a tile to the board",
+        Msg::DocDiscard => "discard a tile which can never be played",
+        Msg::DocFound => "found a new corporation",
+        Msg::DocFoundCorp => "the corporation to found",
+        Msg::DocBuy => "buy shares",
+        Msg::DocBuyAmount => "number of shares to buy",
+        Msg::DocBuyCorp => "the corporation to buy shares in",
+        Msg::DocSell => "sell shares",
+        Msg::DocSellAmount => "number of shares to sell",
+        Msg::DocTrade => "trade shares, two-for-one",
+        Msg::DocTradeAmount => "number of shares to trade, two-for-one",
+        Msg::DocMerge => "choose which corporation to merge into another",
+        Msg::DocMergeFrom => "the corporation to merge into another",
+        Msg::DocMergeInto => "the corporation to be merged into",
+        Msg::DocEnd => "trigger the end of the game at the end of your turn",
+        Msg::DocDone => "finish buying shares and end your turn",
+        Msg::DocKeep => "finish selling and trading shares",
+        Msg::DocBorrow => "take a loan from the bank",
+        Msg::DocRepay => "repay your oldest loan",
+        Msg::DocBid => "bid for the shares being auctioned",
+        Msg::DocBidAmount => "the amount to bid",
+        Msg::DocPass => "drop out of the auction",
+        Msg::DocResign => "resign from the game, you will be placed below all remaining players",
+        Msg::DocUndo => "take back your last action",
+        Msg::DocInfo => "show the size, value and shareholders of a corporation",
+        Msg::DocQueryCorp => "the corporation to show",
+        Msg::DocTileQuery => "show what playing a tile would do",
+        Msg::DocMajority => "show who leads the shareholding of a corporation",
+        Msg::DocDeck => "show the tiles you haven't seen yet",
+        Msg::DocHistory => "show what happened in each turn so far",
+        Msg::DocTimeline => "show each player's cash and net worth after every turn",
+        Msg::DocLanguage => "change the language the game is shown to you in",
+        Msg::DocLanguageLocale => "the language to use",
+        Msg::ErrFinished => "the game is already finished",
+        Msg::ErrNotYourTurn => "not your turn",
+        Msg::ErrCantPlay => "you can't play a tile right now",
+        Msg::ErrCantDiscard => "you can't discard a tile right now",
+        Msg::ErrCantFound => "not able to found a corporation at the moment",
+        Msg::ErrCantBuy => "can't buy shares at the moment",
+        Msg::ErrCantDone => "can't end your turn at the moment",
+        Msg::ErrCantMerge => "can't choose a merger at the moment",
+        Msg::ErrCantSellOrTrade => "not currently in a sell or trade phase",
+        Msg::ErrCantEnd => "can't end the game at the moment",
+        Msg::ErrCantBorrow => "can't borrow at the moment",
+        Msg::ErrCantRepay => "you have no loans to repay at the moment",
+        Msg::ErrCantBid => "there is no auction to bid in",
+        Msg::ErrTileNotInHand => "you don't have {0}",
+        Msg::ErrTileNotDead => "{0} can still be played, only dead tiles can be discarded",
+        Msg::ErrDiscardLimit => "you can only exchange one tile per turn",
+        Msg::ErrWouldMergeSafeCorps => "can't merge safe corporations together ({0})",
+        Msg::ErrNoCorpAvailable => "there aren't any corporations available to found",
+        Msg::ErrCorpOnBoard => "{0} is already on the board",
+        Msg::ErrCorpNotOnBoard => "{0} is not on the board",
+        Msg::ErrZeroAmount => "you must specify an amount greater than 0",
+        Msg::ErrBuyLimit => "can only buy {0} more",
+        Msg::ErrBankOut => "{0} only has {1} left",
+        Msg::ErrInsufficientFunds => "costs ${0}, you only have ${1}",
+        Msg::ErrLoanLimit => "you can only have {0} loans at once",
+        Msg::ErrBankCantLend => "the bank only has ${0} to lend",
+        Msg::ErrBidTooLow => "the lowest bid you can make is ${0}",
+        Msg::ErrInsufficientShares => "you only have {0} {1}",
+        Msg::ErrInvalidTradeAmount => {
+            "can't trade {0}, you can only trade multiples of 2, trades are 2-for-1"
+        }
+        Msg::ErrSameCorpMerge => "can't merge the same corp into itself",
+        Msg::ErrInvalidMergeFrom => "{0} is not a valid corporation to be merged",
+        Msg::ErrInvalidMergeInto => "{0} is not a valid corporation to merge into",
+        Msg::ErrEndDuringOtherTurn => "can't end the game during another player's turn",
+        Msg::ErrAlreadyResigned => "you have already resigned",
+        Msg::ErrNoSuchPlayer => "there is no player {0}",
+        Msg::ErrUndoOtherPlayer => "can't undo another player's decision",
+        Msg::ErrNothingToUndo => "there is nothing to undo",
+        Msg::ErrUndoTilesDrawn => "can't undo as tiles have been drawn since",
+        Msg::ErrUndoDiceRolled => "can't undo as the dummy player has rolled for shares",
+        Msg::ErrUndoOtherDecision => "can't undo as another player has made a decision since",
+        Msg::ErrUndoTimedOut => "can't undo as you ran out of time",
+        Msg::ErrUndoIrreversible => "can't undo your last action",
+        Msg::ErrNoBasePrice => "the price table has no base price for {0}",
+        Msg::ErrBracketsOutOfOrder => "price table brackets must be in ascending order of size",
+        Msg::ErrInvalidRoster => {
+            "the roster needs at least two corporations with unique names, abbreviations and \
+             slots"
+        }
+        Msg::ErrBoardTooSmall => "the board needs at least {0} cells",
+        Msg::ErrBankTooSmall => "the bank needs at least ${0} for starting cash",
+        Msg::ErrInvalidTeams => {
+            "teams must be of equal size with at least 2 players each and include all {0} \
+             players exactly once"
+        }
+    }
+}
+
+fn de(msg: Msg) -> &'static str {
+    match msg {
+        Msg::TwoPlayerRule => "\
+Sonderregel für 2 Spieler: Für die Aktionärsprämien wird ein Strohmann hinzugefügt. Ein \
+Würfel (W6) bestimmt die Aktien des Strohmanns. Das Geld des Strohmanns wird nicht gezählt und er \
+kann das Spiel nicht gewinnen.",
+        Msg::WillStart => "{0} beginnt das Spiel",
+        Msg::Played => "{0} spielte {1}",
+        Msg::Discarded => "{0} warf {1} ab",
+        Msg::YouDrew => "Du hast {0} gezogen",
+        Msg::IncreasedInSize => "{0} wuchs auf Größe {1}",
+        Msg::Founded => "{0} gründete {1}",
+        Msg::Bought => "{0} kaufte {1} {2} für {3}",
+        Msg::PayingBonuses => "Aktionärsprämien für {0} werden ausgezahlt",
+        Msg::RedrawHand => {
+            "{0} hat keine spielbaren Plättchen und zieht eine neue Hand, abgeworfen: {1}"
+        }
+        Msg::DiscardedDead => "{0} warf {1} ab, da es nie gespielt werden kann",
+        Msg::TileDead => "{0} (nie spielbar)",
+        Msg::TileBlocked => "{0} (nicht spielbar, bis eine Gesellschaft verfügbar ist)",
+        Msg::SkipPlacement => {
+            "{0} hat keine spielbaren Plättchen und legt in diesem Zug keines: {1}"
+        }
+        Msg::MergingInto => "{0} wird von {1} übernommen",
+        Msg::DummyRolled => "Der Strohmann würfelte {0}",
+        Msg::MajorBonus => "Mehrheitsprämie von {0} an {1}",
+        Msg::MinorBonus => "Minderheitsprämie von {0} an {1}",
+        Msg::NoShareholders => "Niemand hält Aktien von {0}, daher werden keine Prämien gezahlt",
+        Msg::DummyPlayer => "Strohmann",
+        Msg::Sold => "{0} verkaufte {1} {2} für {3}",
+        Msg::Borrowed => "{0} lieh sich {1} und schuldet {2}",
+        Msg::Repaid => "{0} zahlte einen Kredit über {1} zurück",
+        Msg::LoanDue => "Ein Kredit von {0} ist fällig, {1} wurde gezahlt, {2} bleiben geschuldet",
+        Msg::LoansSettled => "{0} zahlte {1} der geschuldeten {2} an Krediten zurück",
+        Msg::AuctionStarted => "{0} Aktien von {1} werden versteigert",
+        Msg::BidPlaced => "{0} bot {1}",
+        Msg::PassedBid => "{0} passte",
+        Msg::SealedBidMade => "{0} gab ein verdecktes Gebot ab",
+        Msg::SealedBids => "Die verdeckten Gebote waren {0}",
+        Msg::BidPassed => "gepasst",
+        Msg::AuctionWon => "{0} ersteigerte {1} Aktien von {2} für {3}",
+        Msg::AuctionNoBids => "Niemand bot für die Aktien von {0}",
+        Msg::AuctionWaiting => "Versteigerung von {0}, es fehlen Gebote von {1}",
+        Msg::AuctionHighBid => "Versteigerung von {0}, das Höchstgebot ist {1} von {2}",
+        Msg::AuctionOpening => "Versteigerung von {0}, noch keine Gebote",
+        Msg::MarketMoved => "Aktien von {0} bewegten sich von {1} auf {2}",
+        Msg::Traded => "{0} tauschte {1} {2} gegen {3} {4}",
+        Msg::Kept => "{0} behielt {1} {2}",
+        Msg::TriggeredEnd => "{0} hat das Spielende zum Ende des Zuges ausgelöst, da {1}",
+        Msg::ResignedBot => "{0} hat aufgegeben, ein Bot spielt die restlichen Züge",
+        Msg::ResignedLiquidate => {
+            "{0} hat aufgegeben, die Aktien werden verkauft und die Plättchen abgeworfen"
+        }
+        Msg::AllResigned => "Alle Spieler haben aufgegeben, das Spiel endet",
+        Msg::ReturnedToBank => "{0} gab {1} {2} an die Bank zurück",
+        Msg::TimeoutPlay => "{0} hat die Zeit überschritten, {1} wird automatisch gespielt",
+        Msg::TimeoutFound => "{0} hat die Zeit überschritten, {1} wird automatisch gegründet",
+        Msg::TimeoutDone => "{0} hat die Zeit überschritten, der Aktienkauf wird beendet",
+        Msg::TimeoutMerge => {
+            "{0} hat die Zeit überschritten, {1} wird automatisch von {2} übernommen"
+        }
+        Msg::TimeoutKeep => "{0} hat die Zeit überschritten, die Aktien werden behalten",
+        Msg::TimeoutOther => "{0} hat die Zeit überschritten, der Zug wird automatisch gespielt",
+        Msg::Undid => "{0} hat die letzte Aktion zurückgenommen",
+        Msg::LanguageChanged => "Das Spiel wird jetzt auf {0} angezeigt",
+        Msg::PlayerDrew => "{0} zog {1}",
+        Msg::FinalCash => "Geld am Spielende: {0}",
+        Msg::HoldingsHidden => "Aktienbesitz ist in diesem Spiel verdeckt",
+        Msg::TiedBrokenBy => "{0} sind mit {1} gleichauf, platziert nach {2}",
+        Msg::TiedShared => "{0} sind mit {1} gleichauf und teilen sich die Platzierung",
+        Msg::TiebreakMostShares => "meisten Aktien bei Spielende",
+        Msg::TiebreakMostMajorBonuses => "meisten Mehrheitsprämien",
+        Msg::TiebreakTurnOrder => "früherer Position in der Zugreihenfolge",
+        Msg::HeaderCashBefore => "Geld davor",
+        Msg::HeaderSale => "Verkauf",
+        Msg::HeaderLoans => "Kredite",
+        Msg::HeaderFinalCash => "Geld am Ende",
+        Msg::HeaderPlacing => "Platz",
+        Msg::HeaderBehind => "Rückstand",
+        Msg::HeaderTurn => "Zug",
+        Msg::HeaderTile => "Plättchen",
+        Msg::HeaderFounded => "Gegründet",
+        Msg::HeaderMerged => "Fusioniert",
+        Msg::HeaderBought => "Gekauft",
+        Msg::HistoryMerged => "{0} in {1}",
+        Msg::HistoryEmpty => "Es wurde noch kein Zug beendet",
+        Msg::TimelineKey => "Geld / Nettovermögen nach jedem Zug",
+        Msg::InfoNotOnBoard => {
+            "{0} ist nicht auf dem Spielplan, Aktien sind bei der Gründung {1} wert"
+        }
+        Msg::InfoSafe => "{0} hat Größe {1} und Aktien sind {2} wert, sie ist sicher",
+        Msg::InfoNotSafe => "{0} hat Größe {1} und Aktien sind {2} wert, sie ist nicht sicher",
+        Msg::InfoNextBracket => "Aktien sind bei Größe {1} {0} wert",
+        Msg::InfoMaxValue => "Aktien haben ihren Höchstwert erreicht",
+        Msg::InfoBankShares => "{0} Aktien verbleiben in der Bank",
+        Msg::InfoNoHolders => "Niemand hält Aktien",
+        Msg::InfoHolders => "Aktionäre: {0}",
+        Msg::TileOccupied => "{0} kann nicht gespielt werden, es liegt bereits auf dem Spielplan",
+        Msg::TileSafeMerge => {
+            "{0} kann nicht gespielt werden, es würde die sicheren Gesellschaften {1} fusionieren"
+        }
+        Msg::TileNoCorp => {
+            "{0} kann nicht gespielt werden, es würde eine Gesellschaft gründen, aber keine ist \
+             verfügbar"
+        }
+        Msg::TileUnincorporated => "{0} würde keiner Gesellschaft angehören",
+        Msg::TileFound => "{0} würde eine neue Gesellschaft gründen",
+        Msg::TileGrow => "{0} würde {1} auf Größe {2} vergrößern",
+        Msg::TileMergeChoice => {
+            "{0} würde {1} fusionieren, der Spieler wählt die überlebende Gesellschaft"
+        }
+        Msg::TileMerge => "{0} würde {1} mit {2} fusionieren",
+        Msg::MajorityNobody => "Niemand hält Aktien von {0}",
+        Msg::MajorityOnly => "{0} ist mit {2} der einzige Aktionär von {1}",
+        Msg::MajorityTied => "{0} führen gemeinsam bei {1} mit {2}",
+        Msg::MajorityLeads => "{0} führt bei {1} mit {2}, {4} vor {3}",
+        Msg::DeckUnseen => "{0} ungesehene Plättchen: {1}",
+        Msg::DeckFoundChance => {
+            "Chance, ein Plättchen zu ziehen, das eine Gesellschaft gründet: {0}"
+        }
+        Msg::HeaderCorporation => "Gesellschaft",
+        Msg::HeaderSize => "Größe",
+        Msg::HeaderValue => "Wert",
+        Msg::HeaderShares => "Aktien",
+        Msg::HeaderMinor => "Minderheit",
+        Msg::HeaderMajor => "Mehrheit",
+        Msg::HeaderMajorHolder => "Mehrheitsaktionär",
+        Msg::HeaderMinorHolder => "Minderheitsaktionär",
+        Msg::HeaderPlayer => "Spieler",
+        Msg::HeaderCash => "Geld",
+        Msg::HeaderTiles => "Plättchen",
+        Msg::HeaderWorth => "Vermögen",
+        Msg::HeaderDebt => "Schulden",
+        Msg::HeaderTeam => "Team",
+        Msg::TeamName => "Team {0}",
+        Msg::SharesLeft => "{0} übrig",
+        Msg::DrawTilesRemaining => "Verbleibende Plättchen: {0}",
+        Msg::SeatBot => " (Bot)",
+        Msg::SeatResigned => " (aufgegeben)",
+        Msg::Tied => " (gleichauf)",
+        Msg::EndTriggered => "Das Spiel endet am Ende dieses Zuges",
+        Msg::EndFinished => "Das Spiel ist beendet",
+        Msg::EndCanTrigger => "Das Spielende kann ausgelöst werden",
+        Msg::EndReasonLargest => "{0} {1} Plättchen erreicht hat",
+        Msg::EndReasonAllSafe => "alle Konzerne auf dem Spielplan sicher sind",
+        Msg::AutoEnded => "Das Spiel endet automatisch, da {0}",
+        Msg::AdviseEnd => "Du kannst das Spiel mit end beenden, da {0}",
+        Msg::ProjectedStandings => "Wenn das Spiel jetzt enden würde: {0}",
+        Msg::ProjectedCash => "Wenn das Spiel jetzt enden würde, hättest du {0}",
+        Msg::BankShortfall => "Der Bank ist das Geld ausgegangen, sie konnte nur {0} der \
+                               geschuldeten {1} auszahlen",
+        Msg::BankBalance => "Geld in der Bank: {0}",
+        Msg::NoCorpsFounded => "Es wurden noch keine Gesellschaften gegründet",
+        Msg::LargestCorp => "Die größte Gesellschaft hat {0} von {1}, {2} unsicher verbleibend",
+        Msg::DocPlay => "ein Plättchen auf den Spielplan legen",
+        Msg::DocDiscard => "ein Plättchen abwerfen, das nie gespielt werden kann",
+        Msg::DocFound => "eine neue Gesellschaft gründen",
+        Msg::DocFoundCorp => "die zu gründende Gesellschaft",
+        Msg::DocBuy => "Aktien kaufen",
+        Msg::DocBuyAmount => "Anzahl der zu kaufenden Aktien",
+        Msg::DocBuyCorp => "die Gesellschaft, deren Aktien gekauft werden",
+        Msg::DocSell => "Aktien verkaufen",
+        Msg::DocSellAmount => "Anzahl der zu verkaufenden Aktien",
+        Msg::DocTrade => "Aktien zwei zu eins tauschen",
+        Msg::DocTradeAmount => "Anzahl der zu tauschenden Aktien, zwei zu eins",
+        Msg::DocMerge => "wählen, welche Gesellschaft übernommen wird",
+        Msg::DocMergeFrom => "die Gesellschaft, die übernommen wird",
+        Msg::DocMergeInto => "die übernehmende Gesellschaft",
+        Msg::DocEnd => "das Spielende zum Ende deines Zuges auslösen",
+        Msg::DocDone => "den Aktienkauf beenden und den Zug abschließen",
+        Msg::DocKeep => "das Verkaufen und Tauschen von Aktien beenden",
+        Msg::DocBorrow => "einen Kredit bei der Bank aufnehmen",
+        Msg::DocRepay => "deinen ältesten Kredit zurückzahlen",
+        Msg::DocBid => "für die versteigerten Aktien bieten",
+        Msg::DocBidAmount => "der gebotene Betrag",
+        Msg::DocPass => "aus der Versteigerung aussteigen",
+        Msg::DocResign => {
+            "das Spiel aufgeben, du wirst hinter allen verbleibenden Spielern platziert"
+        }
+        Msg::DocUndo => "deine letzte Aktion zurücknehmen",
+        Msg::DocInfo => "Größe, Wert und Aktionäre einer Gesellschaft anzeigen",
+        Msg::DocQueryCorp => "die anzuzeigende Gesellschaft",
+        Msg::DocTileQuery => "anzeigen, was das Spielen eines Plättchens bewirken würde",
+        Msg::DocMajority => "anzeigen, wer bei den Aktien einer Gesellschaft führt",
+        Msg::DocDeck => "die Plättchen anzeigen, die du noch nicht gesehen hast",
+        Msg::DocHistory => "anzeigen, was in jedem bisherigen Zug passiert ist",
+        Msg::DocTimeline => "Geld und Nettovermögen aller Spieler nach jedem Zug anzeigen",
+        Msg::DocLanguage => "die Sprache ändern, in der dir das Spiel angezeigt wird",
+        Msg::DocLanguageLocale => "die zu verwendende Sprache",
+        Msg::ErrFinished => "das Spiel ist bereits beendet",
+        Msg::ErrNotYourTurn => "du bist nicht am Zug",
+        Msg::ErrCantPlay => "du kannst gerade kein Plättchen spielen",
+        Msg::ErrCantDiscard => "du kannst gerade kein Plättchen abwerfen",
+        Msg::ErrCantFound => "gerade kann keine Gesellschaft gegründet werden",
+        Msg::ErrCantBuy => "gerade können keine Aktien gekauft werden",
+        Msg::ErrCantDone => "du kannst deinen Zug gerade nicht beenden",
+        Msg::ErrCantMerge => "gerade kann keine Fusion gewählt werden",
+        Msg::ErrCantSellOrTrade => "gerade können keine Aktien verkauft oder getauscht werden",
+        Msg::ErrCantEnd => "das Spiel kann gerade nicht beendet werden",
+        Msg::ErrCantBorrow => "du kannst gerade keinen Kredit aufnehmen",
+        Msg::ErrCantRepay => "du hast gerade keinen Kredit zurückzuzahlen",
+        Msg::ErrCantBid => "es gibt keine Versteigerung, in der du bieten kannst",
+        Msg::ErrTileNotInHand => "du hast {0} nicht",
+        Msg::ErrTileNotDead => {
+            "{0} kann noch gespielt werden, nur nie spielbare Plättchen können abgeworfen werden"
+        }
+        Msg::ErrDiscardLimit => "du kannst nur ein Plättchen pro Zug tauschen",
+        Msg::ErrWouldMergeSafeCorps => "sichere Gesellschaften können nicht fusionieren ({0})",
+        Msg::ErrNoCorpAvailable => "es sind keine Gesellschaften zum Gründen verfügbar",
+        Msg::ErrCorpOnBoard => "{0} ist bereits auf dem Spielplan",
+        Msg::ErrCorpNotOnBoard => "{0} ist nicht auf dem Spielplan",
+        Msg::ErrZeroAmount => "die Anzahl muss größer als 0 sein",
+        Msg::ErrBuyLimit => "du kannst nur noch {0} kaufen",
+        Msg::ErrBankOut => "{0} hat nur noch {1} übrig",
+        Msg::ErrInsufficientFunds => "kostet ${0}, du hast nur ${1}",
+        Msg::ErrLoanLimit => "du kannst nur {0} Kredite gleichzeitig haben",
+        Msg::ErrBankCantLend => "die Bank hat nur ${0} zu verleihen",
+        Msg::ErrBidTooLow => "das niedrigste mögliche Gebot ist ${0}",
+        Msg::ErrInsufficientShares => "du hast nur {0} {1}",
+        Msg::ErrInvalidTradeAmount => {
+            "{0} kann nicht getauscht werden, es wird zwei zu eins getauscht, also nur \
+             Vielfache von 2"
+        }
+        Msg::ErrSameCorpMerge => "eine Gesellschaft kann nicht mit sich selbst fusionieren",
+        Msg::ErrInvalidMergeFrom => "{0} kann nicht übernommen werden",
+        Msg::ErrInvalidMergeInto => "{0} kann nicht übernehmen",
+        Msg::ErrEndDuringOtherTurn => {
+            "das Spiel kann nicht während des Zuges eines anderen Spielers beendet werden"
+        }
+        Msg::ErrAlreadyResigned => "du hast bereits aufgegeben",
+        Msg::ErrNoSuchPlayer => "es gibt keinen Spieler {0}",
+        Msg::ErrUndoOtherPlayer => "die Entscheidung eines anderen Spielers kann nicht \
+                                    zurückgenommen werden",
+        Msg::ErrNothingToUndo => "es gibt nichts zurückzunehmen",
+        Msg::ErrUndoTilesDrawn => {
+            "kann nicht zurückgenommen werden, da seitdem Plättchen gezogen wurden"
+        }
+        Msg::ErrUndoDiceRolled => {
+            "kann nicht zurückgenommen werden, da der Strohmann für Aktien gewürfelt hat"
+        }
+        Msg::ErrUndoOtherDecision => {
+            "kann nicht zurückgenommen werden, da seitdem ein anderer Spieler entschieden hat"
+        }
+        Msg::ErrUndoTimedOut => "kann nicht zurückgenommen werden, da deine Zeit abgelaufen ist",
+        Msg::ErrUndoIrreversible => "deine letzte Aktion kann nicht zurückgenommen werden",
+        Msg::ErrNoBasePrice => "die Preistabelle hat keinen Grundpreis für {0}",
+        Msg::ErrBracketsOutOfOrder => {
+            "die Stufen der Preistabelle müssen nach aufsteigender Größe geordnet sein"
+        }
+        Msg::ErrInvalidRoster => {
+            "die Aufstellung braucht mindestens zwei Gesellschaften mit eindeutigen Namen, \
+             Kürzeln und Plätzen"
+        }
+        Msg::ErrBoardTooSmall => "der Spielplan braucht mindestens {0} Felder",
+        Msg::ErrBankTooSmall => "die Bank braucht mindestens ${0} für das Startgeld",
+        Msg::ErrInvalidTeams => {
+            "Teams müssen gleich groß sein, mindestens 2 Spieler haben und alle {0} Spieler \
+             genau einmal enthalten"
+        }
+    }
+}
+
+impl Locale {
+    pub fn template(self, msg: Msg) -> &'static str {
+        match self {
+            Locale::En => en(msg),
+            Locale::De => de(msg),
+        }
+    }
+
+    /// Fill a template with markup arguments. Each argument is used at most once.
+    pub fn fill(self, msg: Msg, args: Vec<N>) -> Vec<N> {
+        let mut args: Vec<Option<N>> = args.into_iter().map(Some).collect();
+        let mut nodes: Vec<N> = vec![];
+        let mut rest = self.template(msg);
+        while let Some(start) = rest.find('{') {
+            let end = match rest[start..].find('}') {
+                Some(e) => start + e,
+                None => break,
+            };
+            if start > 0 {
+                nodes.push(N::text(&rest[..start]));
+            }
+            match rest[start + 1..end]
+                .parse::<usize>()
+                .ok()
+                .and_then(|i| args.get_mut(i))
+                .and_then(|a| a.take())
+            {
+                Some(n) => nodes.push(n),
+                None => nodes.push(N::text(&rest[start..end + 1])),
+            }
+            rest = &rest[end + 1..];
+        }
+        if !rest.is_empty() {
+            nodes.push(N::text(rest));
+        }
+        nodes
+    }
+
+    /// Fill a template with plain text arguments.
+    pub fn format(self, msg: Msg, args: &[String]) -> String {
+        let mut s = self.template(msg).to_string();
+        for (i, arg) in args.iter().enumerate() {
+            s = s.replace(&format!("{{{}}}", i), arg);
+        }
+        s
+    }
+}
+
+impl Game {
+    /// The locale a player has chosen, or the game's locale for anyone else.
+    pub fn locale(&self, player: usize) -> Locale {
+        self.players
+            .get(player)
+            .map(|p| p.locale)
+            .unwrap_or(self.options.locale)
+    }
+
+    pub fn set_locale(&mut self, player: usize, locale: Locale) -> Result<Vec<Log>, AcquireError> {
+        if player >= self.players.len() {
+            return Err(AcquireError::NoSuchPlayer(player));
+        }
+        self.players[player].locale = locale;
+        Ok(vec![Log::private(
+            locale.fill(Msg::LanguageChanged, vec![N::text(format!("{}", locale))]),
+            vec![player],
+        )])
+    }
+
+    /// Logs for everyone. There is always a public log in the game's locale for spectators and
+    /// the game's public record.
+    pub(crate) fn public_logs<F>(&self, content: F) -> Vec<Log>
+    where
+        F: Fn(Locale) -> Vec<N>,
+    {
+        self.public_logs_except(&[], content)
+    }
+
+    /// Logs for everyone apart from `except`. The public log is only addressed to players in
+    /// the game's locale, and each group of players who have chosen a different locale gets a
+    /// private copy instead, so every player sees the log once.
+    pub(crate) fn public_logs_except<F>(&self, except: &[usize], content: F) -> Vec<Log>
+    where
+        F: Fn(Locale) -> Vec<N>,
+    {
+        let (local, others): (Vec<usize>, Vec<usize>) = (0..self.players.len())
+            .filter(|p| !except.contains(p))
+            .partition(|p| self.locale(*p) == self.options.locale);
+        let mut public = Log::public(content(self.options.locale));
+        public.to = local;
+        let mut logs = vec![public];
+        logs.extend(self.private_logs(&others, content));
+        logs
+    }
+
+    /// Logs for some players, with a private copy for each locale among them.
+    pub(crate) fn private_logs<F>(&self, players: &[usize], content: F) -> Vec<Log>
+    where
+        F: Fn(Locale) -> Vec<N>,
+    {
+        LOCALES
+            .iter()
+            .filter_map(|locale| {
+                let to: Vec<usize> = players
+                    .iter()
+                    .filter(|p| self.locale(**p) == *locale)
+                    .cloned()
+                    .collect();
+                if to.is_empty() {
+                    None
+                } else {
+                    Some(Log::private(content(*locale), to))
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use brdgme_game::Gamer;
+
+    use super::*;
+    use crate::corp::Corp;
+    use crate::roster::Roster;
+
+    #[test]
+    fn fill_orders_arguments() {
+        assert_eq!(
+            vec![
+                N::text("Aktien sind bei Größe "),
+                N::text("6"),
+                N::text(" "),
+                N::text("$600"),
+                N::text(" wert"),
+            ],
+            Locale::De.fill(
+                Msg::InfoNextBracket,
+                vec![N::text("$600"), N::text("6")]
+            )
+        );
+    }
+
+    #[test]
+    fn format_works() {
+        assert_eq!(
+            "costs $800, you only have $500",
+            Locale::En.format(
+                Msg::ErrInsufficientFunds,
+                &["800".to_string(), "500".to_string()]
+            )
+        );
+    }
+
+    #[test]
+    fn logs_split_by_player_locale() {
+        let players = vec!["mick".to_string(), "steve".to_string()];
+        let mut g: Game = "...
+                           .0.
+                           ..1"
+            .into();
+        g.command(0, "language de", &players)
+            .expect("expected changing language to work");
+        assert_eq!(Locale::De, g.player_state(0).locale);
+        assert_eq!(Locale::En, g.player_state(1).locale);
+        let resp = g.command(0, "play b2", &players)
+            .expect("expected 'play b2' to work");
+        assert_eq!(2, resp.logs.len());
+        assert!(resp.logs[0].public);
+        assert_eq!(vec![1], resp.logs[0].to);
+        assert!(!resp.logs[1].public);
+        assert_eq!(vec![0], resp.logs[1].to);
+        for p in 0..2 {
+            assert_eq!(1, resp.logs.iter().filter(|l| l.to.contains(&p)).count());
+        }
+        assert_eq!(
+            "du bist nicht am Zug",
+            AcquireError::NotYourTurn.localise(g.locale(0), &g.options.roster)
+        );
+        assert_eq!(
+            "Luxor is not on the board",
+            AcquireError::CorpNotOnBoard(Corp::Sackson).localise(Locale::En, &Roster::modern())
+        );
+    }
+}
@@ -0,0 +1,174 @@
+use crate::board::{Board, Loc, Tile, TileEffect};
+use crate::corp::Corp;
+use crate::{Game, PlayerState};
+
+/// Tiles which aren't visible on the board or in the given hand.
+pub fn unseen_tiles(board: &Board, hand: &[Loc]) -> Vec<Loc> {
+    board
+        .geometry
+        .locs()
+        .into_iter()
+        .filter(|l| board.get_tile(l) == Tile::Empty && !hand.contains(l))
+        .collect()
+}
+
+/// The chance that none of `matching` tiles out of `total` are in `drawn` randomly drawn tiles.
+fn chance_none(total: usize, matching: usize, drawn: usize) -> f64 {
+    if matching == 0 {
+        return 1.0;
+    }
+    if drawn + matching > total {
+        return 0.0;
+    }
+    (0..drawn).fold(1.0, |acc, i| {
+        acc * (total - matching - i) as f64 / (total - i) as f64
+    })
+}
+
+/// What a player can deduce about the tiles they can't see. Unseen tiles are either in the draw
+/// pile or in opponents' hands, and as tiles are shuffled every unseen tile is equally likely to
+/// be in any of those places.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TileKnowledge {
+    pub board: Board,
+    pub unseen: Vec<Loc>,
+    pub draw_pile: usize,
+    pub opponents: usize,
+}
+
+impl TileKnowledge {
+    pub fn new(state: &PlayerState) -> Self {
+        // Opponents' tiles are known when playing with open tiles.
+        let mut seen: Vec<Loc> = state.tiles.clone();
+        let mut opponents: usize = 0;
+        for (p, pub_player) in state.public.players.iter().enumerate() {
+            if p == state.player {
+                continue;
+            }
+            match pub_player.tiles {
+                Some(ref tiles) => seen.extend(tiles),
+                None => opponents += 1,
+            }
+        }
+        TileKnowledge {
+            board: state.public.board.clone(),
+            unseen: unseen_tiles(&state.public.board, &seen),
+            draw_pile: state.public.remaining_tiles,
+            opponents,
+        }
+    }
+
+    /// The total number of tiles held by opponents.
+    pub fn opponent_tiles(&self) -> usize {
+        self.unseen.len().saturating_sub(self.draw_pile)
+    }
+
+    /// Unseen tiles which would have an effect matching the predicate if played now.
+    pub fn matching<F>(&self, pred: F) -> Vec<Loc>
+    where
+        F: Fn(&TileEffect) -> bool,
+    {
+        self.unseen
+            .iter()
+            .filter(|l| pred(&self.board.tile_effect(l)))
+            .cloned()
+            .collect()
+    }
+
+    /// Unseen tiles which would merge `from` into `into`, including merges where the player would
+    /// have to choose between them.
+    pub fn merge_tiles(&self, from: &Corp, into: &Corp) -> Vec<Loc> {
+        self.matching(|e| match *e {
+            TileEffect::Merge {
+                from: ref f,
+                into: ref i,
+            } => f.contains(from) && i.contains(into),
+            _ => false,
+        })
+    }
+
+    pub fn founding_tiles(&self) -> Vec<Loc> {
+        self.matching(|e| *e == TileEffect::Found)
+    }
+
+    /// The chance that at least one of `matching` unseen tiles is drawn in the next `draws` draws.
+    pub fn chance_drawn(&self, matching: usize, draws: usize) -> f64 {
+        1.0 - chance_none(self.unseen.len(), matching, draws.min(self.draw_pile))
+    }
+
+    /// The chance that any opponent holds at least one of `matching` unseen tiles.
+    pub fn chance_opponents_hold(&self, matching: usize) -> f64 {
+        1.0 - chance_none(self.unseen.len(), matching, self.opponent_tiles())
+    }
+
+    /// The chance that a specific opponent holds at least one of `matching` unseen tiles.
+    pub fn chance_opponent_holds(&self, matching: usize) -> f64 {
+        if self.opponents == 0 {
+            return 0.0;
+        }
+        1.0 - chance_none(
+            self.unseen.len(),
+            matching,
+            self.opponent_tiles() / self.opponents,
+        )
+    }
+
+    /// The chance that any opponent holds a tile merging `from` into `into`.
+    pub fn chance_opponents_merge(&self, from: &Corp, into: &Corp) -> f64 {
+        self.chance_opponents_hold(self.merge_tiles(from, into).len())
+    }
+
+    /// The chance of drawing a tile next turn which would found a corporation on the current
+    /// board.
+    pub fn chance_draw_founding(&self) -> f64 {
+        self.chance_drawn(self.founding_tiles().len(), 1)
+    }
+}
+
+impl Game {
+    /// Tiles which aren't visible to the player, either still in the draw pile or in another
+    /// player's hand.
+    pub fn unseen_tiles(&self, player: usize) -> Vec<Loc> {
+        let seen: Vec<Loc> = self.players
+            .iter()
+            .enumerate()
+            .filter(|&(p, _)| self.is_teammate(player, p) || self.tiles_visible())
+            .flat_map(|(_, state)| state.tiles.clone())
+            .collect();
+        unseen_tiles(&self.board, &seen)
+    }
+
+    pub fn tile_knowledge(&self, player: usize) -> TileKnowledge {
+        TileKnowledge::new(&self.player_state(player))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chance_none_works() {
+        assert_eq!(1.0, chance_none(10, 0, 5));
+        assert_eq!(0.0, chance_none(10, 6, 5));
+        assert!((chance_none(10, 1, 1) - 0.9).abs() < 1e-9);
+        // 8/10 * 7/9
+        assert!((chance_none(10, 2, 2) - 56.0 / 90.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn tile_knowledge_works() {
+        let g: Game = "#.0
+                       ...
+                       1..".into();
+        let k = g.tile_knowledge(0);
+        assert_eq!(g.unseen_tiles(0), k.unseen);
+        assert_eq!(106, k.unseen.len());
+        assert_eq!(0, k.draw_pile);
+        assert_eq!(106, k.opponent_tiles());
+        // A2 and B1 both found, and both must be in the opponent's hand with no draw pile.
+        assert_eq!(vec![Loc { row: 0, col: 1 }, Loc { row: 1, col: 0 }], k.founding_tiles());
+        assert_eq!(1.0, k.chance_opponents_hold(2));
+        assert_eq!(0.0, k.chance_draw_founding());
+    }
+}
@@ -1,8 +1,33 @@
+pub mod auction;
+mod bank;
 pub mod board;
 mod command;
 pub mod corp;
+pub mod delta;
+mod discard;
+pub mod errors;
+pub mod history;
+pub mod i18n;
+pub mod knowledge;
+pub mod loans;
+pub mod majority;
+pub mod market;
+pub mod options;
+pub mod prices;
+mod query;
 mod render;
+pub mod report;
+pub mod roster;
+pub mod save;
+pub mod seat;
 mod stats;
+pub mod teams;
+mod tiebreak;
+mod trigger;
+mod timer;
+pub mod undo;
+mod visibility;
+pub mod worth;
 
 use rand::{thread_rng, Rng};
 use serde_derive::{Serialize, Deserialize};
@@ -15,10 +40,24 @@ use brdgme_markup::Node as N;
 
 use std::collections::HashMap;
 
+use crate::auction::Bid;
 use crate::board::{Board, Loc, Tile};
 use crate::command::Command;
-use crate::corp::Corp;
+use crate::corp::{Corp, CORPS};
+use crate::errors::{AcquireError, Action};
+use crate::history::TurnRecord;
+use crate::i18n::{Locale, Msg};
+use crate::loans::Loan;
+use crate::market::Market;
+use crate::options::{DeadTileRule, Options};
+use crate::prices::PriceTable;
+use crate::render::comma_list;
+use crate::report::EndGameReport;
+use crate::roster::Roster;
+use crate::seat::Seat;
 use crate::stats::Stats;
+use crate::undo::{UndoBarrier, UndoPoint};
+use crate::visibility::redacted;
 
 pub const MIN_PLAYERS: usize = 2;
 pub const MAX_PLAYERS: usize = 6;
@@ -50,6 +89,21 @@ pub enum Phase {
         at: Loc,
         turn_player: usize,
     },
+    /// Every player bids once, in secret, for the first shares of a newly founded corporation.
+    SealedBid {
+        player: usize,
+        corp: Corp,
+        bids: Vec<Bid>,
+    },
+    /// Players take turns raising the bid for the first shares of a newly founded corporation.
+    OpenBid {
+        player: usize,
+        corp: Corp,
+        bidder: usize,
+        /// The highest bidder and their bid.
+        high: Option<(usize, usize)>,
+        passed: Vec<usize>,
+    },
 }
 
 impl Phase {
@@ -60,6 +114,11 @@ impl Phase {
             | Phase::Buy { player, .. }
             | Phase::ChooseMerger { player, .. }
             | Phase::SellOrTrade { player, .. } => player,
+            Phase::SealedBid { player, ref bids, .. } => (0..bids.len())
+                .map(|offset| (player + offset) % bids.len())
+                .find(|p| bids[*p] == Bid::Pending)
+                .unwrap_or(player),
+            Phase::OpenBid { bidder, .. } => bidder,
         }
     }
 
@@ -68,7 +127,9 @@ impl Phase {
             Phase::Play(player)
             | Phase::Found { player, .. }
             | Phase::Buy { player, .. }
-            | Phase::ChooseMerger { player, .. } => player,
+            | Phase::ChooseMerger { player, .. }
+            | Phase::SealedBid { player, .. }
+            | Phase::OpenBid { player, .. } => player,
             Phase::SellOrTrade { turn_player, .. } => turn_player,
         }
     }
@@ -89,6 +150,26 @@ pub struct PubState {
     pub remaining_tiles: usize,
     pub last_turn: bool,
     pub finished: bool,
+    /// The locale the game is shown in to anyone who isn't a player.
+    #[serde(default)]
+    pub locale: Locale,
+    /// Seats grouped into partnerships, empty when playing without teams.
+    #[serde(default)]
+    pub teams: Vec<Vec<usize>>,
+    /// How the game was settled, once it has finished.
+    #[serde(default)]
+    pub end_report: Option<EndGameReport>,
+    /// The chart share prices are read from.
+    #[serde(default)]
+    pub prices: PriceTable,
+    #[serde(default)]
+    pub roster: Roster,
+    /// Money held by the bank when it is limited.
+    #[serde(default)]
+    pub bank: Option<usize>,
+    /// Share price movement when using the market variant.
+    #[serde(default)]
+    pub market: Option<Market>,
 }
 
 impl PubState {
@@ -123,6 +204,19 @@ impl PubState {
             unsafe_count,
         }.into()
     }
+
+    /// Which end condition has been met, if the end of the game can be triggered.
+    pub fn end_reason(&self) -> Option<EndReason> {
+        if self.can_end() != CanEnd::True {
+            return None;
+        }
+        Some(
+            Corp::iter()
+                .find(|c| self.board.corp_size(c) >= corp::GAME_END_SIZE)
+                .map(|c| EndReason::LargestCorp(*c))
+                .unwrap_or(EndReason::AllSafe),
+        )
+    }
 }
 
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
@@ -130,6 +224,9 @@ pub struct PlayerState {
     pub public: PubState,
     pub player: usize,
     pub tiles: Vec<Loc>,
+    /// The locale the player has chosen to view the game in.
+    #[serde(default)]
+    pub locale: Locale,
 }
 
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
@@ -141,6 +238,41 @@ pub struct Game {
     pub shares: HashMap<Corp, usize>,
     pub last_turn: bool,
     pub finished: bool,
+    #[serde(default)]
+    pub options: Options,
+    /// When the current player's turn started, in seconds since the Unix epoch. The engine
+    /// doesn't read the clock, so this is `None` until the host calls `start_turn_clock`.
+    #[serde(default)]
+    pub turn_started_at: Option<u64>,
+    /// Players who have resigned, in the order they resigned.
+    #[serde(default)]
+    pub resigned: Vec<usize>,
+    #[serde(default)]
+    pub undo_stack: Vec<UndoPoint>,
+    /// Why the undo stack was last cleared.
+    #[serde(default)]
+    pub undo_barrier: Option<UndoBarrier>,
+    /// Dead tiles the current player has discarded this turn.
+    #[serde(default)]
+    pub turn_discards: usize,
+    /// The player who took the first turn.
+    #[serde(default)]
+    pub start_player: usize,
+    /// How the game was settled, once it has finished.
+    #[serde(default)]
+    pub end_report: Option<EndGameReport>,
+    /// Money held by the bank, only tracked when the bank is limited.
+    #[serde(default)]
+    pub bank: Option<usize>,
+    /// Share price movement, only tracked when using the market variant.
+    #[serde(default)]
+    pub market: Option<Market>,
+    /// Every completed turn, oldest first.
+    #[serde(default)]
+    pub turn_history: Vec<TurnRecord>,
+    /// What has happened so far in the turn in progress.
+    #[serde(default)]
+    pub current_turn: TurnRecord,
 }
 
 impl Default for Game {
@@ -150,9 +282,21 @@ impl Default for Game {
             players: vec![],
             board: Board::default(),
             draw_tiles: vec![],
-            shares: corp_hash_map(STARTING_SHARES),
+            shares: corp_hash_map(STARTING_SHARES, &CORPS),
             last_turn: false,
             finished: false,
+            options: Options::default(),
+            turn_started_at: None,
+            resigned: vec![],
+            undo_stack: vec![],
+            undo_barrier: None,
+            turn_discards: 0,
+            start_player: 0,
+            end_report: None,
+            bank: None,
+            market: None,
+            turn_history: vec![],
+            current_turn: TurnRecord::default(),
         }
     }
 }
@@ -162,89 +306,72 @@ impl Gamer for Game {
     type PlayerState = PlayerState;
 
     fn new(players: usize) -> Result<(Self, Vec<Log>), GameError> {
-        let mut g = Game::default();
-        if players < MIN_PLAYERS || players > MAX_PLAYERS {
-            return Err(GameError::PlayerCount {
-                min: MIN_PLAYERS,
-                max: MAX_PLAYERS,
-                given: players,
-            });
-        }
-
-        // Shuffle up the draw tiles.
-        let mut tiles = Loc::all();
-        thread_rng().shuffle(tiles.as_mut_slice());
-        g.draw_tiles = tiles;
-
-        // Place initial tiles onto the board.
-        for l in g.draw_tiles.drain(0..players) {
-            g.board.set_tile(&l, Tile::Unincorporated);
-        }
-
-        // Setup for each player.
-        for _ in 0..players {
-            let mut player = Player::default();
-            player.tiles = g.draw_tiles.drain(0..TILE_HAND_SIZE).collect();
-            g.players.push(player);
-        }
-
-        // Set the start player.
-        let start_player = (thread_rng().next_u32() as usize) % players;
-        g.phase = Phase::Play(start_player);
-
-        let mut logs: Vec<Log> = vec![];
-        if players == 2 {
-            // 2 players gets a dummy shareholder, output details.
-            logs.push(Log::public(vec![N::Bold(vec![
-                N::text(
-                    "\
-2 player special rule: a dummy player is added for shareholder bonuses. A dice (D6) is rolled to \
-determine the dummy player's shares. The money for the dummy player is not tracked and it is not \
-able to win the game."
-                ),
-            ])]))
-        }
-        logs.push(Log::public(vec![
-            N::Player(start_player),
-            N::text(" will start the game"),
-        ]));
-
-        Ok((g, logs))
+        Game::new_with_options(players, Options::default())
     }
 
     fn status(&self) -> Status {
         if self.finished {
             Status::Finished {
                 placings: self.placings(),
-                stats: vec![],
+                stats: self.players
+                    .iter()
+                    .enumerate()
+                    .map(|(p, player)| {
+                        let mut stats = player.stats.to_brdgme_stats();
+                        if let Some(ref report) = self.end_report {
+                            stats.extend(report.players[p].to_brdgme_stats());
+                        }
+                        if let Some(ref market) = self.market {
+                            let names: Vec<(Corp, String)> = self.options
+                                .roster
+                                .corps()
+                                .iter()
+                                .map(|c| (*c, self.options.roster.name(c)))
+                                .collect();
+                            stats.extend(market.to_brdgme_stats(&names));
+                        }
+                        stats
+                    })
+                    .collect(),
             }
         } else {
             Status::Active {
-                whose_turn: vec![self.phase.whose_turn()],
-                eliminated: vec![],
+                whose_turn: self.phase.acting(),
+                eliminated: self.resigned.clone(),
             }
         }
     }
 
     fn placings(&self) -> Vec<usize> {
+        // Resigned players are placed below everyone still playing, with those who resigned
+        // earlier placed lower.
         gen_placings(
-            self.players
-                .iter()
-                .map(|p| vec![p.money as i32])
+            (0..self.players.len())
+                .map(|i| match self.resigned.iter().position(|r| *r == i) {
+                    Some(order) => vec![order as i32, 0],
+                    None => {
+                        // Tiebreaks only separate players finishing with the same score.
+                        let mut values =
+                            vec![self.resigned.len() as i32, self.team_score(i) as i32];
+                        values.extend(self.tiebreak_values(i));
+                        values
+                    }
+                })
                 .collect::<Vec<Vec<i32>>>()
                 .as_ref(),
         )
     }
 
     fn pub_state(&self) -> Self::PubState {
-        self.to_owned().into()
+        self.pub_state_for(None)
     }
 
     fn player_state(&self, player: usize) -> Self::PlayerState {
         PlayerState {
-            public: self.pub_state(),
+            public: self.pub_state_for(Some(player)),
             player,
             tiles: self.players[player].tiles.to_owned(),
+            locale: self.locale(player),
         }
     }
 
@@ -255,22 +382,27 @@ able to win the game."
         players: &[String],
     ) -> Result<CommandResponse, GameError> {
         let parser = self.command_parser(player).ok_or_else::<GameError, _>(|| {
-            GameError::InvalidInput {
-                message: "not your turn".to_string(),
+            if self.finished {
+                AcquireError::Finished.into()
+            } else {
+                AcquireError::NoSuchPlayer(player).into()
             }
         })?;
         let output = parser.parse(input, players)?;
-        match output.value {
-            Command::Play(loc) => self.handle_play_command(player, &loc),
-            Command::Found(corp) => self.handle_found_command(player, &corp),
-            Command::Buy(n, corp) => self.handle_buy_command(player, n, corp),
-            Command::Done => self.handle_done_command(player).map(|l| (l, false)),
-            Command::Merge(corp, into) => self.handle_merge_command(player, &corp, &into),
-            Command::Sell(n) => self.handle_sell_command(player, n),
-            Command::Trade(n) => self.handle_trade_command(player, n),
-            Command::Keep => self.handle_keep_command(player),
-            Command::End => self.handle_end_command(player).map(|l| (l, false)),
-        }.map(|(logs, can_undo)| CommandResponse {
+        let whose_turn = self.phase.whose_turn();
+        let locale = self.locale(player);
+        let roster = self.options.roster.clone();
+        let command = output.value;
+        let (mut logs, can_undo) = if command.is_undoable() {
+            self.run_undoable_command(player, command)
+        } else {
+            self.run_command(player, command)
+        }.map_err(|e| e.into_game_error(locale, &roster))?;
+        logs.extend(self.run_bots().map_err(|e| e.into_game_error(locale, &roster))?);
+        if self.phase.whose_turn() != whose_turn {
+            self.turn_started_at = None;
+        }
+        Ok(CommandResponse {
             logs,
             can_undo,
             remaining_input: output.remaining.to_string(),
@@ -290,8 +422,12 @@ able to win the game."
     }
 
     fn points(&self) -> Vec<f32> {
+        // Scores would reveal hidden cash, so they're only given once visible.
+        if !self.money_visible() {
+            return vec![0.0; self.players.len()];
+        }
         (0..self.players.len())
-            .map(|p| self.player_score(p) as f32)
+            .map(|p| self.team_score(p) as f32)
             .collect()
     }
 }
@@ -317,13 +453,131 @@ pub enum CanEnd {
     False(CanEndFalse),
 }
 
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum EndReason {
+    /// A corporation has reached the game end size.
+    LargestCorp(Corp),
+    /// Every corporation on the board is safe.
+    AllSafe,
+}
+
+/// Players receiving bonuses, grouped by the holder they belong to. Each group is a single player
+/// unless partners' shares are combined.
 struct BonusPlayers {
-    major: Vec<usize>,
-    minor: Vec<usize>,
+    major: Vec<Vec<usize>>,
+    minor: Vec<Vec<usize>>,
     dummy_shares: usize,
 }
 
 impl Game {
+    pub fn new_with_options(
+        players: usize,
+        options: Options,
+    ) -> Result<(Self, Vec<Log>), GameError> {
+        let mut g = Game {
+            options,
+            ..Game::default()
+        };
+        if players < MIN_PLAYERS || players > MAX_PLAYERS {
+            return Err(GameError::PlayerCount {
+                min: MIN_PLAYERS,
+                max: MAX_PLAYERS,
+                given: players,
+            });
+        }
+        // Invalid options are reported in the game's locale, naming corporations as its roster
+        // does.
+        let locale = g.options.locale;
+        let roster = g.options.roster.clone();
+        let invalid = |e: AcquireError| e.into_game_error(locale, &roster);
+        teams::validate_teams(&g.options.teams, players).map_err(&invalid)?;
+        g.options
+            .prices
+            .validate(&g.options.roster.corps())
+            .map_err(&invalid)?;
+        g.options.roster.validate().map_err(&invalid)?;
+        g.options
+            .geometry
+            .validate(players * (TILE_HAND_SIZE + 1))
+            .map_err(&invalid)?;
+        let corps = g.options.roster.corps();
+        g.board = Board::new(g.options.geometry.clone(), corps.clone());
+        g.shares = corp_hash_map(STARTING_SHARES, &corps);
+        if let Some(limit) = g.options.bank {
+            g.bank = Some(limit.opening_balance(players).map_err(&invalid)?);
+        }
+        if g.options.market.is_some() {
+            g.market = Some(Market::default());
+        }
+
+        // Shuffle up the draw tiles.
+        let mut tiles = g.board.geometry.locs();
+        thread_rng().shuffle(tiles.as_mut_slice());
+        g.draw_tiles = tiles;
+
+        // Place initial tiles onto the board.
+        for l in g.draw_tiles.drain(0..players) {
+            g.board.set_tile(&l, Tile::Unincorporated);
+        }
+
+        // Setup for each player.
+        for _ in 0..players {
+            let mut player = Player::default();
+            player.locale = g.options.locale;
+            player.shares = corp_hash_map(0, &corps);
+            player.tiles = g.draw_tiles.drain(0..TILE_HAND_SIZE).collect();
+            g.players.push(player);
+        }
+
+        // Set the start player.
+        let start_player = (thread_rng().next_u32() as usize) % players;
+        g.phase = Phase::Play(start_player);
+        g.start_player = start_player;
+
+        let mut logs: Vec<Log> = vec![];
+        if players == 2 {
+            // 2 players gets a dummy shareholder, output details.
+            logs.extend(g.public_logs(|l| {
+                vec![N::Bold(vec![N::text(l.template(Msg::TwoPlayerRule))])]
+            }));
+        }
+        logs.extend(g.public_logs(|l| l.fill(Msg::WillStart, vec![N::Player(start_player)])));
+
+        Ok((g, logs))
+    }
+
+    fn run_command(
+        &mut self,
+        player: usize,
+        command: Command,
+    ) -> Result<(Vec<Log>, bool), AcquireError> {
+        match command {
+            Command::Play(loc) => self.handle_play_command(player, &loc),
+            Command::Discard(loc) => self.handle_discard_command(player, &loc),
+            Command::Found(corp) => self.handle_found_command(player, &corp),
+            Command::Buy(n, corp) => self.handle_buy_command(player, n, corp),
+            Command::Done => self.handle_done_command(player).map(|l| (l, false)),
+            Command::Merge(corp, into) => self.handle_merge_command(player, &corp, &into),
+            Command::Sell(n) => self.handle_sell_command(player, n),
+            Command::Trade(n) => self.handle_trade_command(player, n),
+            Command::Keep => self.handle_keep_command(player),
+            Command::Bid(n) => self.handle_bid_command(player, n),
+            Command::Pass => self.handle_pass_command(player),
+            Command::Borrow => self.handle_borrow_command(player),
+            Command::Repay => self.handle_repay_command(player),
+            Command::End => self.handle_end_command(player).map(|l| (l, false)),
+            Command::Resign => self.handle_resign_command(player).map(|l| (l, false)),
+            Command::Undo => self.undo(player).map(|l| (l, false)),
+            Command::Info(corp) => Ok((self.info_query(player, &corp), false)),
+            Command::Tile(loc) => Ok((self.tile_query(player, &loc), false)),
+            Command::Majority(corp) => Ok((self.majority_query(player, &corp), false)),
+            Command::Deck => Ok((self.deck_query(player), false)),
+            Command::History => Ok((self.history_query(player), false)),
+            Command::Timeline => Ok((self.timeline_query(player), false)),
+            Command::Language(locale) => self.set_locale(player, locale).map(|l| (l, false)),
+        }
+    }
+
     pub fn can_play(&self, player: usize) -> bool {
         match self.phase {
             Phase::Play(p) if p == player => true,
@@ -331,33 +585,25 @@ impl Game {
         }
     }
 
-    fn draw_replacement_tiles(&mut self, player: usize) -> Result<(Vec<Log>, bool), GameError> {
-        // Discard permanently unplayable tiles.
-        let (mut keep, discard): (Vec<Loc>, Vec<Loc>) = self.players[player]
-            .tiles
-            .iter()
-            .partition(|loc| !self.board.loc_neighbours_multiple_safe_corps(loc));
+    fn draw_replacement_tiles(&mut self, player: usize) -> Result<(Vec<Log>, bool), AcquireError> {
+        // Discard permanently unplayable tiles, unless players discard them themselves.
+        let auto_discard = self.options.dead_tile_rule == DeadTileRule::AutoDiscard;
+        let (mut keep, discard): (Vec<Loc>, Vec<Loc>) =
+            self.players[player].tiles.iter().partition(|loc| {
+                !auto_discard || !self.board.loc_neighbours_multiple_safe_corps(loc)
+            });
         let mut logs: Vec<Log> = vec![];
         if !discard.is_empty() {
             self.board.set_discarded(&discard);
-            logs.push(Log::public(vec![
-                N::Player(player),
-                N::text(" discarded "),
-                N::Group(
-                    discard
-                        .iter()
-                        .enumerate()
-                        .flat_map(|(i, d)| {
-                            let mut ns: Vec<N> = vec![];
-                            if i > 0 {
-                                ns.push(N::text(", "));
-                            }
-                            ns.push(d.render());
-                            ns
-                        })
-                        .collect(),
-                ),
-            ]))
+            logs.extend(self.public_logs(|l| {
+                l.fill(
+                    Msg::DiscardedDead,
+                    vec![
+                        N::Player(player),
+                        comma_list(discard.iter().map(|d| d.render()).collect()),
+                    ],
+                )
+            }));
         }
         let remaining = TILE_HAND_SIZE - keep.len();
         if self.draw_tiles.len() < remaining {
@@ -366,26 +612,7 @@ impl Game {
             return Ok((logs, true));
         }
         let new_tiles: Vec<Loc> = self.draw_tiles.drain(0..remaining).collect();
-        logs.push(Log::private(
-            vec![
-                N::text("You drew "),
-                N::Group(
-                    new_tiles
-                        .iter()
-                        .enumerate()
-                        .flat_map(|(i, d)| {
-                            let mut ns: Vec<N> = vec![];
-                            if i > 0 {
-                                ns.push(N::text(", "));
-                            }
-                            ns.push(d.render());
-                            ns
-                        })
-                        .collect(),
-                ),
-            ],
-            vec![player],
-        ));
+        logs.extend(self.drawn_tiles_logs(player, &new_tiles));
         keep.extend(new_tiles);
         self.players[player].tiles = keep;
         Ok((logs, false))
@@ -395,51 +622,52 @@ impl Game {
         &mut self,
         player: usize,
         loc: &Loc,
-    ) -> Result<(Vec<Log>, bool), GameError> {
-        self.assert_not_finished()?;
-        self.assert_player_turn(player)?;
+    ) -> Result<(Vec<Log>, bool), AcquireError> {
+        self.ensure_not_finished()?;
+        self.ensure_player_turn(player)?;
 
         let mut can_undo = true;
 
         if !self.can_play(player) {
-            return Err(GameError::InvalidInput {
-                message: "You can't play a tile right now".to_string(),
-            });
+            return Err(AcquireError::WrongPhase(Action::Play));
         }
         let pos = match self.players[player].tiles.iter().position(|l| l == loc) {
             Some(p) => p,
             None => {
-                return Err(GameError::InvalidInput {
-                    message: "You don't have that tile".to_string(),
-                });
+                return Err(AcquireError::TileNotInHand(*loc));
             }
         };
-        let mut logs: Vec<Log> = vec![Log::public(vec![
-            N::Player(player),
-            N::text(" played "),
-            N::Bold(vec![N::text(format!("{}", loc))]),
-        ])];
+        let mut logs: Vec<Log> = self.public_logs(|l| {
+            l.fill(
+                Msg::Played,
+                vec![N::Player(player), N::Bold(vec![N::text(format!("{}", loc))])],
+            )
+        });
         let neighbouring_corps = self.board.neighbouring_corps(loc);
         match neighbouring_corps.len() {
             1 => {
                 let n_corp = neighbouring_corps.iter().next().unwrap();
                 self.board.extend_corp(loc, n_corp);
-                logs.push(Log::public(vec![
-                    n_corp.render(),
-                    N::text(" increased in size to "),
-                    N::Bold(vec![N::text(format!("{}", self.board.corp_size(n_corp)))]),
-                ]));
+                logs.extend(self.public_logs(|l| {
+                    l.fill(
+                        Msg::IncreasedInSize,
+                        vec![
+                            self.render_corp(&n_corp),
+                            N::Bold(vec![N::text(format!("{}", self.board.corp_size(n_corp)))]),
+                        ],
+                    )
+                }));
                 self.buy_phase(player);
             }
             0 => {
-                let has_unincorporated_neighbour = loc.neighbours()
+                let has_unincorporated_neighbour = self.board
+                    .geometry
+                    .neighbours(loc)
                     .iter()
                     .any(|n_loc| self.board.get_tile(n_loc) == Tile::Unincorporated);
                 if has_unincorporated_neighbour {
                     if self.board.available_corps().is_empty() {
-                        return Err(GameError::InvalidInput {
-                            message: "there aren't any corporations available to found".to_string(),
-                        });
+                        return Err(AcquireError::NoCorpAvailable);
                     }
                     self.found_phase(player, loc.to_owned());
                 } else {
@@ -449,17 +677,12 @@ impl Game {
                 self.board.set_tile(loc, Tile::Unincorporated);
             }
             _ => {
-                let safe_corp_count = neighbouring_corps.iter().fold(0, |acc, corp| {
-                    if self.board.corp_is_safe(corp) {
-                        acc + 1
-                    } else {
-                        acc
-                    }
-                });
-                if safe_corp_count > 1 {
-                    return Err(GameError::InvalidInput {
-                        message: "can't merge safe corporations together".to_string(),
-                    });
+                let safe_corps: Vec<Corp> = Corp::iter()
+                    .filter(|c| neighbouring_corps.contains(c) && self.board.corp_is_safe(c))
+                    .cloned()
+                    .collect();
+                if safe_corps.len() > 1 {
+                    return Err(AcquireError::WouldMergeSafeCorps(safe_corps));
                 }
                 self.board.set_tile(loc, Tile::Unincorporated);
                 let (new_logs, new_can_undo) = self.choose_merger_phase(player, *loc)?;
@@ -468,6 +691,7 @@ impl Game {
             }
         }
         self.players[player].tiles.swap_remove(pos);
+        self.current_turn.tile = Some(*loc);
         Ok((logs, can_undo))
     }
 
@@ -489,7 +713,7 @@ impl Game {
         &mut self,
         player: usize,
         loc: Loc,
-    ) -> Result<(Vec<Log>, bool), GameError> {
+    ) -> Result<(Vec<Log>, bool), AcquireError> {
         let (from, into) = self.board.merge_candidates(&loc);
         if from.is_empty() {
             // No mergers, go to buy phase.
@@ -514,24 +738,22 @@ impl Game {
         &mut self,
         player: usize,
         corp: &Corp,
-    ) -> Result<(Vec<Log>, bool), GameError> {
-        self.assert_not_finished()?;
-        self.assert_player_turn(player)?;
+    ) -> Result<(Vec<Log>, bool), AcquireError> {
+        self.ensure_not_finished()?;
+        self.ensure_player_turn(player)?;
         let at = match self.phase {
             Phase::Found { at, .. } => at,
             _ => {
-                return Err(GameError::InvalidInput {
-                    message: "not able to found a corporation at the moment".to_string(),
-                });
+                return Err(AcquireError::WrongPhase(Action::Found));
             }
         };
         if !self.board.available_corps().contains(corp) {
-            return Err(GameError::InvalidInput {
-                message: format!("{} is already on the board", corp),
-            });
+            return Err(AcquireError::CorpOnBoard(*corp));
         }
         self.players[player].stats.founds.push(*corp);
+        self.current_turn.founded.push(*corp);
         self.board.extend_corp(&at, corp);
+        self.market_reset(corp);
         {
             let corp_shares = self.shares.entry(*corp).or_insert(STARTING_SHARES);
             if *corp_shares > 0 {
@@ -540,13 +762,12 @@ impl Game {
                 *corp_shares -= 1;
             }
         }
-        self.buy_phase(player);
+        let mut logs = self.public_logs(|l| {
+            l.fill(Msg::Founded, vec![N::Player(player), self.render_corp(&corp)])
+        });
+        logs.extend(self.start_auction(player, *corp));
         Ok((
-            vec![Log::public(vec![
-                N::Player(player),
-                N::text(" founded "),
-                corp.render(),
-            ])],
+            logs,
             match self.phase {
                 Phase::Buy { .. } => true,
                 _ => false,
@@ -559,42 +780,41 @@ impl Game {
         player: usize,
         n: usize,
         corp: Corp,
-    ) -> Result<(Vec<Log>, bool), GameError> {
-        self.assert_not_finished()?;
-        self.assert_player_turn(player)?;
+    ) -> Result<(Vec<Log>, bool), AcquireError> {
+        self.ensure_not_finished()?;
+        self.ensure_player_turn(player)?;
         if n == 0 {
-            return Err(GameError::InvalidInput {
-                message: "can't buy 0 shares".to_string(),
-            });
+            return Err(AcquireError::ZeroAmount);
         }
         match self.phase {
             Phase::Buy { remaining, .. } => {
                 if n > remaining {
-                    return Err(GameError::InvalidInput {
-                        message: format!("can only buy {} more", remaining),
-                    });
+                    return Err(AcquireError::BuyLimit { remaining });
                 }
                 let corp_size = self.board.corp_size(&corp);
                 if corp_size == 0 {
-                    return Err(GameError::InvalidInput {
-                        message: format!("{} is not on the board", corp),
-                    });
+                    return Err(AcquireError::CorpNotOnBoard(corp));
                 }
                 let corp_shares = self.shares.get(&corp).cloned().unwrap_or(0);
                 if n > corp_shares {
-                    return Err(GameError::InvalidInput {
-                        message: format!("{} has {} left", corp, corp_shares),
+                    return Err(AcquireError::BankOut {
+                        corp,
+                        left: corp_shares,
                     });
                 }
-                let price = corp.value(corp_size) * n;
+                let price = self.share_price(&corp) * n;
                 let player_money = self.players[player].money;
                 if price > player_money {
-                    return Err(GameError::InvalidInput {
-                        message: format!("costs ${}, you only have ${}", price, player_money),
+                    return Err(AcquireError::InsufficientFunds {
+                        cost: price,
+                        cash: player_money,
                     });
                 }
                 self.players[player].money -= price;
+                self.bank_receive(price);
                 self.take_shares(player, n, &corp)?;
+                self.market_buy(&corp, n);
+                self.record_buy(corp, n);
                 self.players[player].stats.buy_sum += price;
                 self.players[player].stats.buys += n;
 
@@ -602,47 +822,70 @@ impl Game {
                     player,
                     remaining: remaining - n,
                 };
+                // The price gives away the number of shares, so hiding either hides the price.
+                let hidden = !self.money_visible() || !self.shares_visible();
                 Ok((
-                    vec![Log::public(vec![
-                        N::Player(player),
-                        N::text(" bought "),
-                        N::Bold(vec![N::text(format!("{} ", n))]),
-                        corp.render(),
-                        N::text(" for "),
-                        N::Bold(vec![N::text(format!("${}", price))]),
-                    ])],
+                    self.redacted_logs(&[player], hidden, |l, shown| {
+                        l.fill(
+                            Msg::Bought,
+                            vec![
+                                N::Player(player),
+                                if shown || self.shares_visible() {
+                                    N::Bold(vec![N::text(format!("{}", n))])
+                                } else {
+                                    redacted()
+                                },
+                                self.render_corp(&corp),
+                                if shown {
+                                    N::Bold(vec![N::text(format!("${}", price))])
+                                } else {
+                                    redacted()
+                                },
+                            ],
+                        )
+                    }),
                     true,
                 ))
             }
-            _ => Err(GameError::InvalidInput {
-                message: "can't buy shares at the moment".to_string(),
-            }),
+            _ => Err(AcquireError::WrongPhase(Action::Buy)),
         }
     }
 
-    pub fn handle_done_command(&mut self, player: usize) -> Result<Vec<Log>, GameError> {
-        self.assert_not_finished()?;
-        self.assert_player_turn(player)?;
+    pub fn handle_done_command(&mut self, player: usize) -> Result<Vec<Log>, AcquireError> {
+        self.ensure_not_finished()?;
+        self.ensure_player_turn(player)?;
         match self.phase {
             Phase::Buy { .. } => self.end_turn(),
-            _ => Err(GameError::InvalidInput {
-                message: "can't end your turn at the moment".to_string(),
-            }),
+            _ => Err(AcquireError::WrongPhase(Action::Done)),
         }
     }
 
-    fn end(&mut self) -> Result<Vec<Log>, GameError> {
+    fn end(&mut self) -> Result<Vec<Log>, AcquireError> {
+        self.settle(Game::roll_dummy_shares)
+    }
+
+    /// Pay out every corporation and finish the game, using `dummy_shares` to decide the
+    /// dummy player's holding in each corporation.
+    pub(crate) fn settle<F>(&mut self, dummy_shares: F) -> Result<Vec<Log>, AcquireError>
+    where
+        F: Fn(&Game) -> usize,
+    {
         let mut logs: Vec<Log> = vec![];
         self.finished = true;
+        for player in &mut self.players {
+            player.stats.final_shares = player.shares.values().sum();
+        }
+        let mut report = EndGameReport::new(&self.players);
         // Pay all bonuses on the board.
         for corp in Corp::iter() {
             let size = self.board.corp_size(corp);
             if size > 0 {
-                logs.push(Log::public(vec![N::Bold(vec![
-                    N::text("Paying shareholder bonuses for "),
-                    corp.render(),
-                ])]));
-                logs.extend(self.pay_bonuses(corp));
+                let before: Vec<Stats> = self.players.iter().map(|p| p.stats.clone()).collect();
+                logs.extend(self.public_logs(|l| {
+                    vec![N::Bold(l.fill(Msg::PayingBonuses, vec![self.render_corp(&corp)]))]
+                }));
+                let dummy = dummy_shares(self);
+                logs.extend(self.pay_bonuses(corp, dummy));
                 for player in 0..self.players.len() {
                     let p_shares = *self.players[player]
                         .shares
@@ -652,49 +895,58 @@ impl Game {
                         logs.extend(self.sell(player, p_shares, corp)?);
                     }
                 }
+                report.add_payouts(*corp, &before, &self.players);
             }
         }
+        let cash: Vec<usize> = self.players.iter().map(|p| p.money).collect();
+        logs.extend(self.settle_loans());
+        report.add_loans(&cash, &self.players);
+        if !self.options.visibility.money {
+            // Cash was hidden during the game, reveal it now.
+            logs.extend(self.public_logs(|l| {
+                l.fill(
+                    Msg::FinalCash,
+                    vec![comma_list(
+                        self.players
+                            .iter()
+                            .enumerate()
+                            .map(|(p, state)| {
+                                N::Group(vec![
+                                    N::Player(p),
+                                    N::text(" "),
+                                    N::Bold(vec![N::text(format!("${}", state.money))]),
+                                ])
+                            })
+                            .collect(),
+                    )],
+                )
+            }));
+        }
+        logs.extend(self.tiebreak_logs());
+        report.finish(self);
+        self.end_report = Some(report);
         Ok(logs)
     }
 
-    fn start_turn(&mut self, player: usize) -> Result<Vec<Log>, GameError> {
-        // If all tiles are unplayable, we get new tiles.
-        if self.players[player]
-            .tiles
-            .iter()
-            .find(|loc| self.board.assert_loc_playable(loc).is_ok())
-            .is_none()
-            {
-                let (mut logs, has_ended) = self.redraw_hand(player)?;
-                if !has_ended {
-                    logs.extend(self.start_turn(player)?);
-                }
-                return Ok(logs);
-            }
+    fn start_turn(&mut self, player: usize) -> Result<Vec<Log>, AcquireError> {
         self.phase = Phase::Play(player);
-        Ok(vec![])
+        self.turn_discards = 0;
+        let mut logs = self.loans_due(player);
+        logs.extend(self.check_playable_hand(player)?);
+        logs.extend(self.end_advice(player)?);
+        Ok(logs)
     }
 
-    fn redraw_hand(&mut self, player: usize) -> Result<(Vec<Log>, bool), GameError> {
-        let mut logs: Vec<Log> = vec![Log::public(vec![
-            N::Player(player),
-            N::text(" has no playable tiles and will draw a new hand, discarded "),
-            N::Group(
-                self.players[player]
-                    .tiles
-                    .iter()
-                    .enumerate()
-                    .flat_map(|(i, loc)| {
-                        let mut ns: Vec<N> = vec![];
-                        if i > 0 {
-                            ns.push(N::text(", "));
-                        }
-                        ns.push(loc.render());
-                        ns
-                    })
-                    .collect(),
-            ),
-        ])];
+    fn redraw_hand(&mut self, player: usize) -> Result<(Vec<Log>, bool), AcquireError> {
+        let mut logs: Vec<Log> = self.public_logs(|l| {
+            l.fill(
+                Msg::RedrawHand,
+                vec![
+                    N::Player(player),
+                    self.unplayable_tile_list(l, &self.players[player].tiles),
+                ],
+            )
+        });
         self.board.set_discarded(&self.players[player].tiles);
         self.players[player].tiles = vec![];
         let (rep_logs, has_finished) = self.draw_replacement_tiles(player)?;
@@ -702,15 +954,27 @@ impl Game {
         Ok((logs, has_finished))
     }
 
-    fn end_turn(&mut self) -> Result<Vec<Log>, GameError> {
+    fn end_turn(&mut self) -> Result<Vec<Log>, AcquireError> {
+        let current_player = self.phase.whose_turn();
+        self.record_turn(current_player);
+        let mut logs = self.auto_trigger_end();
         if self.last_turn {
             // End the game
-            return self.end();
+            logs.extend(self.end()?);
+            return Ok(logs);
         }
-        let current_player = self.phase.whose_turn();
-        let (mut logs, has_ended) = self.draw_replacement_tiles(current_player)?;
+        let (draw_logs, has_ended) = if self.players[current_player].seat == Seat::Liquidated {
+            // Liquidated seats don't hold tiles.
+            (vec![], false)
+        } else {
+            self.draw_replacement_tiles(current_player)?
+        };
+        logs.extend(draw_logs);
         if !has_ended {
-            let next_player = self.next_player(current_player);
+            let next_player = self.next_turn_player(current_player);
+            if self.round_ends(current_player, next_player) {
+                logs.extend(self.end_market_round());
+            }
             logs.extend(self.start_turn(next_player)?);
         }
         Ok(logs)
@@ -720,43 +984,44 @@ impl Game {
         (player + 1) % self.players.len()
     }
 
+    /// The next player to take a main turn, skipping liquidated seats.
+    fn next_turn_player(&self, player: usize) -> usize {
+        let mut next = self.next_player(player);
+        while next != player && self.players[next].seat == Seat::Liquidated {
+            next = self.next_player(next);
+        }
+        next
+    }
+
     pub fn handle_merge_command(
         &mut self,
         player: usize,
         from: &Corp,
         into: &Corp,
-    ) -> Result<(Vec<Log>, bool), GameError> {
-        self.assert_not_finished()?;
-        self.assert_player_turn(player)?;
+    ) -> Result<(Vec<Log>, bool), AcquireError> {
+        self.ensure_not_finished()?;
+        self.ensure_player_turn(player)?;
         let mut can_undo = true;
         let at = match self.phase {
             Phase::ChooseMerger { at, .. } => at,
             _ => {
-                return Err(GameError::InvalidInput {
-                    message: "can't choose a merger at the moment".to_string(),
-                });
+                return Err(AcquireError::WrongPhase(Action::Merge));
             }
         };
         if from == into {
-            return Err(GameError::InvalidInput {
-                message: "can't merge the same corp into itself".to_string(),
-            });
+            return Err(AcquireError::SameCorpMerge(*from));
         }
         let (from_candidates, into_candidates) = self.board.merge_candidates(&at);
         if from_candidates.is_empty() || into_candidates.is_empty() {
-            return Err(GameError::Internal {
-                message: "merge was called with an empty from or into candidates".to_string(),
-            });
+            return Err(AcquireError::Internal(
+                "merge was called with an empty from or into candidates".to_string(),
+            ));
         }
         if !from_candidates.contains(from) {
-            return Err(GameError::InvalidInput {
-                message: format!("{} is not a valid corporation to be merged", from),
-            });
+            return Err(AcquireError::InvalidMergeFrom(*from));
         }
         if !into_candidates.contains(into) {
-            return Err(GameError::InvalidInput {
-                message: format!("{} is not a valid corporation to merge into", into),
-            });
+            return Err(AcquireError::InvalidMergeInto(*into));
         }
         if self.board.get_tile(at) == Tile::Unincorporated {
             // We just give the tile to the big corp now to make it visually obvious.
@@ -764,13 +1029,16 @@ impl Game {
             // Make sure we also consume any unincorporated tiles if required.
             self.board.extend_corp(&at, into);
         }
-        let mut logs = vec![Log::public(vec![
-            from.render(),
-            N::text(" is merging into "),
-            into.render(),
-        ])];
+        let mut logs = self.public_logs(|l| {
+            l.fill(
+                Msg::MergingInto,
+                vec![self.render_corp(from), self.render_corp(into)],
+            )
+        });
         self.players[player].stats.merges += 1;
-        logs.extend(self.pay_bonuses(from));
+        self.current_turn.merged.push((*from, *into));
+        let dummy = self.roll_dummy_shares();
+        logs.extend(self.pay_bonuses(from, dummy));
         self.phase = Phase::SellOrTrade {
             player,
             corp: *from,
@@ -788,127 +1056,152 @@ impl Game {
         Ok((logs, can_undo && self.players.len() > 2))
     }
 
-    fn pay_bonuses(&mut self, corp: &Corp) -> Vec<Log> {
+    fn pay_bonuses(&mut self, corp: &Corp, dummy_shares: usize) -> Vec<Log> {
         let BonusPlayers {
             major,
             minor,
             dummy_shares,
-        } = self.bonus_players(corp);
+        } = self.bonus_players(corp, dummy_shares);
 
         let mut logs: Vec<Log> = vec![];
         if dummy_shares > 0 {
-            logs.push(Log::public(vec![
-                N::text("The dummy player rolled "),
-                N::Bold(vec![N::text(format!("{}", dummy_shares))]),
-            ]));
+            logs.extend(self.public_logs(|l| {
+                l.fill(
+                    Msg::DummyRolled,
+                    vec![N::Bold(vec![N::text(format!("{}", dummy_shares))])],
+                )
+            }));
         }
 
         let major_len = major.len();
         let minor_len = minor.len();
         if major_len == 0 {
-            panic!("expected some major bonus players");
+            // Every holder can resign and be liquidated, leaving nobody to pay.
+            logs.extend(
+                self.public_logs(|l| l.fill(Msg::NoShareholders, vec![self.render_corp(corp)])),
+            );
+            return logs;
         }
         let corp_size = self.board.corp_size(corp);
-        let mut major_bonus = corp.major_bonus(corp_size);
-        let minor_bonus = corp.minor_bonus(corp_size);
+        let mut major_bonus = self.options.prices.major_bonus(corp, corp_size);
+        let minor_bonus = self.options.prices.minor_bonus(corp, corp_size);
         if minor_len == 0 {
             // There are multiple majors so they also get the minor bonus
             major_bonus += minor_bonus;
         }
-        // Round up to the nearest 100
-        let major_per = (major_bonus / BONUS_ROUNDING + major_len - 1) / major_len * BONUS_ROUNDING;
-        logs.push(Game::bonus_log(&major, "Major", major_per));
-        for p in &major {
-            if *p == DUMMY_PLAYER_OFFSET {
-                continue;
-            }
-            self.players[*p].money += major_per;
-            self.players[*p].stats.major_bonus_sum += major_per;
-            self.players[*p].stats.major_bonuses += 1;
-        }
+        // Round up to the nearest 100, then split between the members of each holder as teams are
+        // all the same size.
+        let major_per = split_bonus(split_bonus(major_bonus, major_len), major[0].len());
+        let major: Vec<usize> = major.into_iter().flatten().collect();
+        logs.extend(self.bonus_logs(&major, Msg::MajorBonus, major_per));
+        let mut owed: Vec<(usize, usize)> = major
+            .iter()
+            .filter(|p| **p != DUMMY_PLAYER_OFFSET)
+            .map(|p| (*p, major_per))
+            .collect();
+        let major_owed = owed.len();
         if minor_len > 0 {
-            // Round up to the nearest 100
-            let minor_per =
-                (minor_bonus / BONUS_ROUNDING + minor_len - 1) / minor_len * BONUS_ROUNDING;
-            logs.push(Game::bonus_log(&minor, "Minor", minor_per));
-            for p in &minor {
-                if *p == DUMMY_PLAYER_OFFSET {
-                    continue;
-                }
-                self.players[*p].money += minor_per;
-                self.players[*p].stats.minor_bonus_sum += minor_per;
-                self.players[*p].stats.minor_bonuses += 1;
+            let minor_per = split_bonus(split_bonus(minor_bonus, minor_len), minor[0].len());
+            let minor: Vec<usize> = minor.into_iter().flatten().collect();
+            logs.extend(self.bonus_logs(&minor, Msg::MinorBonus, minor_per));
+            owed.extend(
+                minor
+                    .iter()
+                    .filter(|p| **p != DUMMY_PLAYER_OFFSET)
+                    .map(|p| (*p, minor_per)),
+            );
+        }
+        // Major bonuses are owed before minor bonuses if the bank runs short.
+        let (paid, shortfall_logs) = self.bank_pay(&owed);
+        logs.extend(shortfall_logs);
+        for (i, amount) in paid.into_iter().enumerate() {
+            let p = owed[i].0;
+            self.players[p].money += amount;
+            if i < major_owed {
+                self.players[p].stats.major_bonus_sum += amount;
+                self.players[p].stats.major_bonuses += 1;
+            } else {
+                self.players[p].stats.minor_bonus_sum += amount;
+                self.players[p].stats.minor_bonuses += 1;
             }
         }
         logs
     }
 
-    fn bonus_log(players: &[usize], kind: &str, bonus: usize) -> Log {
-        let mut content: Vec<N> = vec![
-            N::text(format!("{} bonus of ", kind)),
-            N::Bold(vec![N::text(format!("${}", bonus))]),
-            N::text(" to "),
-        ];
-        content.extend(players.iter().enumerate().flat_map(|(i, p)| {
-            let mut player_content: Vec<N> = vec![];
-            if i > 0 {
-                player_content.push(N::text(", "));
-            }
-            player_content.push(match *p {
-                DUMMY_PLAYER_OFFSET => N::Bold(vec![N::text("dummy player")]),
-                _ => N::Player(*p),
-            });
-            player_content
-        }));
-        Log::public(content)
+    fn bonus_logs(&self, players: &[usize], msg: Msg, bonus: usize) -> Vec<Log> {
+        let recipients: Vec<usize> = players
+            .iter()
+            .filter(|p| **p != DUMMY_PLAYER_OFFSET)
+            .cloned()
+            .collect();
+        self.redacted_logs(&recipients, !self.money_visible(), |l, shown| {
+            l.fill(
+                msg,
+                vec![
+                    if shown {
+                        N::Bold(vec![N::text(format!("${}", bonus))])
+                    } else {
+                        redacted()
+                    },
+                    comma_list(
+                        players
+                            .iter()
+                            .map(|p| match *p {
+                                DUMMY_PLAYER_OFFSET => {
+                                    N::Bold(vec![N::text(l.template(Msg::DummyPlayer))])
+                                }
+                                _ => N::Player(*p),
+                            })
+                            .collect(),
+                    ),
+                ],
+            )
+        })
     }
 
-    fn bonus_players(&self, corp: &Corp) -> BonusPlayers {
-        let mut major: Vec<usize> = vec![];
-        let mut major_count: usize = 0;
-        let mut dummy_shares: usize = 0;
+    /// Two player games have a dummy player competing for bonuses, holding a random number of
+    /// shares decided by a dice roll for each corporation.
+    fn roll_dummy_shares(&self) -> usize {
         if self.players.len() == 2 {
-            dummy_shares = (thread_rng().gen::<usize>() % 5) + 1;
-            major.push(DUMMY_PLAYER_OFFSET);
-            major_count = dummy_shares;
-        }
-        let mut minor: Vec<usize> = vec![];
-        let mut minor_count: usize = 0;
-        for (player, state) in self.players.iter().enumerate() {
-            let shares = state.shares.get(corp).cloned().unwrap_or(0);
-            if shares == 0 {
-                continue;
-            }
-            if shares > major_count {
-                minor = major;
-                minor_count = major_count;
-                major = vec![];
-                major_count = shares;
-            }
-            if shares == major_count {
-                major.push(player);
-            } else {
-                if shares > minor_count {
-                    minor = vec![];
-                    minor_count = shares;
-                }
-                if shares == minor_count {
-                    minor.push(player);
-                }
-            }
+            (thread_rng().gen::<usize>() % 5) + 1
+        } else {
+            0
         }
-        if major.len() > 1 {
-            // If there are multiple majors, they share the minor bonus too
-            minor = vec![];
+    }
+
+    fn bonus_players(&self, corp: &Corp, dummy_shares: usize) -> BonusPlayers {
+        let mut holdings: Vec<(usize, usize)> = vec![];
+        if dummy_shares > 0 {
+            holdings.push((DUMMY_PLAYER_OFFSET, dummy_shares));
         }
+        let groups = self.bonus_groups();
+        holdings.extend(groups.iter().enumerate().map(|(i, group)| {
+            (
+                i,
+                group
+                    .iter()
+                    .map(|p| self.players[*p].shares.get(corp).cloned().unwrap_or(0))
+                    .sum(),
+            )
+        }));
+        let (major, minor) = majority::major_minor(&holdings);
+        let expand = |holders: Vec<usize>| -> Vec<Vec<usize>> {
+            holders
+                .into_iter()
+                .map(|h| match h {
+                    DUMMY_PLAYER_OFFSET => vec![DUMMY_PLAYER_OFFSET],
+                    _ => groups[h].clone(),
+                })
+                .collect()
+        };
         BonusPlayers {
-            major,
-            minor,
+            major: expand(major),
+            minor: expand(minor),
             dummy_shares,
         }
     }
 
-    fn next_player_sell_trade(&mut self) -> Result<(Vec<Log>, bool), GameError> {
+    fn next_player_sell_trade(&mut self) -> Result<(Vec<Log>, bool), AcquireError> {
         let (mut player, corp, into, at, turn_player) = match self.phase {
             Phase::SellOrTrade {
                 player,
@@ -937,7 +1230,7 @@ impl Game {
         Ok((vec![], true))
     }
 
-    fn end_sell_trade_phase(&mut self) -> Result<(Vec<Log>, bool), GameError> {
+    fn end_sell_trade_phase(&mut self) -> Result<(Vec<Log>, bool), AcquireError> {
         let (corp, into, at, turn_player) = match self.phase {
             Phase::SellOrTrade {
                 corp,
@@ -956,19 +1249,18 @@ impl Game {
         &mut self,
         player: usize,
         n: usize,
-    ) -> Result<(Vec<Log>, bool), GameError> {
-        self.assert_not_finished()?;
-        self.assert_player_turn(player)?;
+    ) -> Result<(Vec<Log>, bool), AcquireError> {
+        self.ensure_not_finished()?;
+        self.ensure_player_turn(player)?;
         let mut can_undo = true;
         let corp = match self.phase {
             Phase::SellOrTrade { corp, .. } => corp,
             _ => {
-                return Err(GameError::InvalidInput {
-                    message: "can't sell or trade at the moment".to_string(),
-                });
+                return Err(AcquireError::WrongPhase(Action::SellOrTrade));
             }
         };
         let mut logs = self.sell(player, n, &corp)?;
+        self.market_sell(&corp, n);
         if *self.players[player]
             .shares
             .get(&corp)
@@ -981,61 +1273,70 @@ impl Game {
         Ok((logs, can_undo))
     }
 
-    fn sell(&mut self, player: usize, n: usize, corp: &Corp) -> Result<Vec<Log>, GameError> {
+    fn sell(&mut self, player: usize, n: usize, corp: &Corp) -> Result<Vec<Log>, AcquireError> {
         if n == 0 {
-            return Err(GameError::InvalidInput {
-                message: "you must sell an amount greater than 0".to_string(),
-            });
+            return Err(AcquireError::ZeroAmount);
         }
-        let money = corp.value(self.board.corp_size(corp)) * n;
+        let money = self.share_price(corp) * n;
         let player_shares = *self.players[player]
             .shares
             .get(corp)
             .expect("could not get player shares");
         if n > player_shares {
-            return Err(GameError::InvalidInput {
-                message: "you don't have that many shares".to_string(),
+            return Err(AcquireError::InsufficientShares {
+                corp: *corp,
+                held: player_shares,
             });
         }
         self.return_shares(player, n, corp)?;
+        let (paid, shortfall_logs) = self.bank_pay(&[(player, money)]);
+        let money = paid[0];
         self.players[player].money += money;
         self.players[player].stats.sell_sum += money;
         self.players[player].stats.sells += n;
-        Ok(vec![Log::public(vec![
-            N::Player(player),
-            N::text(" sold "),
-            N::Bold(vec![N::text(format!("{} ", n))]),
-            corp.render(),
-            N::text(" for "),
-            N::Bold(vec![N::text(format!("${}", money))]),
-        ])])
+        let hidden = !self.money_visible() || !self.shares_visible();
+        let mut logs = self.redacted_logs(&[player], hidden, |l, shown| {
+            l.fill(
+                Msg::Sold,
+                vec![
+                    N::Player(player),
+                    if shown || self.shares_visible() {
+                        N::Bold(vec![N::text(format!("{}", n))])
+                    } else {
+                        redacted()
+                    },
+                    self.render_corp(&corp),
+                    if shown {
+                        N::Bold(vec![N::text(format!("${}", money))])
+                    } else {
+                        redacted()
+                    },
+                ],
+            )
+        });
+        logs.extend(shortfall_logs);
+        Ok(logs)
     }
 
     pub fn handle_trade_command(
         &mut self,
         player: usize,
         n: usize,
-    ) -> Result<(Vec<Log>, bool), GameError> {
-        self.assert_not_finished()?;
-        self.assert_player_turn(player)?;
+    ) -> Result<(Vec<Log>, bool), AcquireError> {
+        self.ensure_not_finished()?;
+        self.ensure_player_turn(player)?;
         // Validate
         let (corp, into) = match self.phase {
             Phase::SellOrTrade { corp, into, .. } => (corp, into),
             _ => {
-                return Err(GameError::InvalidInput {
-                    message: "not currently in a sell or trade phase".to_string(),
-                });
+                return Err(AcquireError::WrongPhase(Action::SellOrTrade));
             }
         };
         if n == 0 {
-            return Err(GameError::InvalidInput {
-                message: "you must specify an amount to trade greater than 0".to_string(),
-            });
+            return Err(AcquireError::ZeroAmount);
         }
         if n % 2 != 0 {
-            return Err(GameError::InvalidInput {
-                message: "you can only trade multiples of 2, trades are 2-for-1".to_string(),
-            });
+            return Err(AcquireError::InvalidTradeAmount { n });
         }
         let corp_shares = self.players[player]
             .shares
@@ -1043,8 +1344,9 @@ impl Game {
             .cloned()
             .expect("could not get player shares");
         if corp_shares < n {
-            return Err(GameError::InvalidInput {
-                message: format!("you only have {} {}", corp_shares, corp),
+            return Err(AcquireError::InsufficientShares {
+                corp,
+                held: corp_shares,
             });
         }
         let receive = n / 2;
@@ -1053,27 +1355,37 @@ impl Game {
             .cloned()
             .expect("could not get into shares");
         if receive > into_shares {
-            return Err(GameError::InvalidInput {
-                message: format!("{} only has {} remaining", into, into_shares),
+            return Err(AcquireError::BankOut {
+                corp: into,
+                left: into_shares,
             });
         }
 
         let mut can_undo = true;
         self.players[player].stats.trades += receive;
-        self.players[player].stats.trade_loss_sum += n * corp.value(self.board.corp_size(&corp));
-        self.players[player].stats.trade_gain_sum +=
-            receive * into.value(self.board.corp_size(&into));
+        self.players[player].stats.trade_loss_sum += n * self.share_price(&corp);
+        self.players[player].stats.trade_gain_sum += receive * self.share_price(&into);
         self.return_shares(player, n, &corp)?;
         self.take_shares(player, receive, &into)?;
-        let mut logs = vec![Log::public(vec![
-            N::Player(player),
-            N::text(" traded "),
-            N::Bold(vec![N::text(format!("{} ", n))]),
-            corp.render(),
-            N::text(" for "),
-            N::Bold(vec![N::text(format!("{} ", receive))]),
-            into.render(),
-        ])];
+        let count = |shown: bool, n: usize| {
+            if shown {
+                N::Bold(vec![N::text(format!("{}", n))])
+            } else {
+                redacted()
+            }
+        };
+        let mut logs = self.redacted_logs(&[player], !self.shares_visible(), |l, shown| {
+            l.fill(
+                Msg::Traded,
+                vec![
+                    N::Player(player),
+                    count(shown, n),
+                    self.render_corp(&corp),
+                    count(shown, receive),
+                    self.render_corp(&into),
+                ],
+            )
+        });
         if n == corp_shares {
             let (new_logs, new_can_undo) = self.next_player_sell_trade()?;
             logs.extend(new_logs);
@@ -1082,13 +1394,14 @@ impl Game {
         Ok((logs, can_undo))
     }
 
-    fn take_shares(&mut self, player: usize, n: usize, corp: &Corp) -> Result<(), GameError> {
+    fn take_shares(&mut self, player: usize, n: usize, corp: &Corp) -> Result<(), AcquireError> {
         let corp_shares = *self.shares
             .get(corp)
             .expect("could not get corp share count");
         if corp_shares < n {
-            return Err(GameError::InvalidInput {
-                message: format!("{} only has {} left", corp, corp_shares),
+            return Err(AcquireError::BankOut {
+                corp: *corp,
+                left: corp_shares,
             });
         }
         let player_shares = self.players[player].shares.entry(*corp).or_insert(0);
@@ -1098,14 +1411,15 @@ impl Game {
         Ok(())
     }
 
-    fn return_shares(&mut self, player: usize, n: usize, corp: &Corp) -> Result<(), GameError> {
+    fn return_shares(&mut self, player: usize, n: usize, corp: &Corp) -> Result<(), AcquireError> {
         let player_shares = *self.players[player]
             .shares
             .get(corp)
             .expect("could not get player share count");
         if player_shares < n {
-            return Err(GameError::InvalidInput {
-                message: format!("only has {} left", player_shares),
+            return Err(AcquireError::InsufficientShares {
+                corp: *corp,
+                held: player_shares,
             });
         }
         let player_shares = self.players[player].shares.entry(*corp).or_insert(0);
@@ -1115,52 +1429,50 @@ impl Game {
         Ok(())
     }
 
-    pub fn handle_keep_command(&mut self, player: usize) -> Result<(Vec<Log>, bool), GameError> {
-        self.assert_not_finished()?;
-        self.assert_player_turn(player)?;
+    pub fn handle_keep_command(&mut self, player: usize) -> Result<(Vec<Log>, bool), AcquireError> {
+        self.ensure_not_finished()?;
+        self.ensure_player_turn(player)?;
         let corp = match self.phase {
             Phase::SellOrTrade { corp, .. } => corp,
             _ => {
-                return Err(GameError::InvalidInput {
-                    message: "not currently in a sell or trade phase".to_string(),
-                });
+                return Err(AcquireError::WrongPhase(Action::SellOrTrade));
             }
         };
-        let mut logs: Vec<Log> = vec![Log::public(vec![
-            N::Player(player),
-            N::text(" kept "),
-            N::Bold(vec![N::text(format!(
-                "{} ",
-                *self.players[player].shares.entry(corp).or_insert(0)
-            ))]),
-            corp.render(),
-        ])];
+        let kept = self.players[player].shares.get(&corp).cloned().unwrap_or(0);
+        let mut logs: Vec<Log> = self.redacted_logs(&[player], !self.shares_visible(), |l, shown| {
+            l.fill(
+                Msg::Kept,
+                vec![
+                    N::Player(player),
+                    if shown {
+                        N::Bold(vec![N::text(format!("{}", kept))])
+                    } else {
+                        redacted()
+                    },
+                    self.render_corp(&corp),
+                ],
+            )
+        });
         let (new_logs, can_undo) = self.next_player_sell_trade()?;
         logs.extend(new_logs);
         Ok((logs, can_undo))
     }
 
-    pub fn handle_end_command(&mut self, player: usize) -> Result<Vec<Log>, GameError> {
-        self.assert_not_finished()?;
+    pub fn handle_end_command(&mut self, player: usize) -> Result<Vec<Log>, AcquireError> {
+        self.ensure_not_finished()?;
         if self.phase.main_turn_player() != player {
-            return Err(GameError::InvalidInput {
-                message: "can't end the game during another player's turn".to_string(),
-            });
-        }
-        if self.pub_state().can_end() != CanEnd::True {
-            return Err(GameError::InvalidInput {
-                message: "can't end the game at the moment".to_string(),
-            });
+            return Err(AcquireError::EndDuringOtherTurn);
         }
+        let reason = self.pub_state()
+            .end_reason()
+            .ok_or(AcquireError::WrongPhase(Action::End))?;
         self.last_turn = true;
-        Ok(vec![Log::public(vec![N::Bold(vec![
-            N::Player(player),
-            N::text(" triggered the end of the game at the end of their turn"),
-        ])])])
-    }
-
-    fn player_score(&self, player: usize) -> usize {
-        self.players[player].money
+        Ok(self.public_logs(|l| {
+            vec![N::Bold(l.fill(
+                Msg::TriggeredEnd,
+                vec![N::Player(player), reason.render(l, &self.options.roster)],
+            ))]
+        }))
     }
 
     fn player_can_end(&self, player: usize) -> bool {
@@ -1174,22 +1486,44 @@ pub struct Player {
     pub shares: HashMap<Corp, usize>,
     pub tiles: Vec<Loc>,
     pub stats: Stats,
+    #[serde(default)]
+    pub seat: Seat,
+    #[serde(default)]
+    pub locale: Locale,
+    /// Loans owed to the bank, oldest first.
+    #[serde(default)]
+    pub loans: Vec<Loan>,
+}
+
+impl Player {
+    /// The total owed on the player's loans.
+    pub fn debt(&self) -> usize {
+        self.loans.iter().map(|l| l.owed).sum()
+    }
 }
 
 impl Default for Player {
     fn default() -> Self {
         Player {
             money: STARTING_MONEY,
-            shares: corp_hash_map(0),
+            shares: corp_hash_map(0, &CORPS),
             tiles: vec![],
             stats: Stats::default(),
+            seat: Seat::default(),
+            locale: Locale::default(),
+            loans: vec![],
         }
     }
 }
 
-fn corp_hash_map(initial: usize) -> HashMap<Corp, usize> {
+/// Split a bonus between a number of holders, rounding up to the nearest 100.
+fn split_bonus(bonus: usize, holders: usize) -> usize {
+    (bonus / BONUS_ROUNDING + holders - 1) / holders * BONUS_ROUNDING
+}
+
+fn corp_hash_map(initial: usize, corps: &[Corp]) -> HashMap<Corp, usize> {
     let mut hm: HashMap<Corp, usize> = HashMap::new();
-    for corp in Corp::iter() {
+    for corp in corps {
         hm.insert(*corp, initial);
     }
     hm
@@ -1202,9 +1536,16 @@ impl Into<PubState> for Game {
             players: self.players.iter().map(|v| v.to_owned().into()).collect(),
             board: self.board,
             shares: self.shares,
+            bank: self.bank,
+            market: self.market,
             remaining_tiles: self.draw_tiles.len(),
             last_turn: self.last_turn,
             finished: self.finished,
+            locale: self.options.locale,
+            teams: self.options.teams,
+            end_report: self.end_report,
+            prices: self.options.prices,
+            roster: self.options.roster,
         }
     }
 }
@@ -1212,16 +1553,27 @@ impl Into<PubState> for Game {
 impl Into<PubPlayer> for Player {
     fn into(self) -> PubPlayer {
         PubPlayer {
-            money: self.money,
-            shares: self.shares,
+            debt: self.debt(),
+            money: Some(self.money),
+            shares: Some(self.shares),
+            seat: self.seat,
+            tiles: Some(self.tiles),
         }
     }
 }
 
+/// A player as seen by others. Holdings hidden by the visibility options are `None`.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PubPlayer {
-    pub money: usize,
-    pub shares: HashMap<Corp, usize>,
+    pub money: Option<usize>,
+    pub shares: Option<HashMap<Corp, usize>>,
+    #[serde(default)]
+    pub seat: Seat,
+    #[serde(default)]
+    pub tiles: Option<Vec<Loc>>,
+    /// Owed on loans, which is always public.
+    #[serde(default)]
+    pub debt: usize,
 }
 
 #[cfg(test)]
@@ -1244,6 +1596,7 @@ impl<'a> From<&'a str> for Game {
         g.players = players;
         g.board = s.into();
         g.draw_tiles = vec![];
+        g.turn_started_at = None;
         g
     }
 }
@@ -1331,4 +1684,26 @@ mod tests {
                 .into()
         );
     }
+
+    #[test]
+    fn queries_work_out_of_turn() {
+        let players = vec!["mick".to_string(), "steve".to_string()];
+        let mut g: Game = "FF0
+                           ..1"
+            .into();
+        g.players[0].shares.insert(Corp::Festival, 3);
+        let before = g.clone();
+        for input in &["info fe", "tile a3", "majority fe", "deck"] {
+            let resp = g.command(1, input, &players)
+                .expect("expected query to work out of turn");
+            assert!(!resp.logs.is_empty());
+            assert!(resp.logs.iter().all(|l| !l.public));
+        }
+        assert_eq!(before, g);
+        assert_eq!(105, g.unseen_tiles(1).len());
+        assert!(g.command_spec(1).is_some());
+        assert!(g.command_spec(2).is_none());
+        g.finished = true;
+        assert!(g.command_spec(0).is_none());
+    }
 }
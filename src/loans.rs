@@ -0,0 +1,207 @@
+use serde_derive::{Serialize, Deserialize};
+
+use brdgme_game::Log;
+use brdgme_markup::Node as N;
+
+use crate::errors::{AcquireError, Action};
+use crate::i18n::Msg;
+use crate::visibility::redacted;
+use crate::{Game, Phase};
+
+/// Money borrowed from the bank.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Loan {
+    /// What is left to repay, including interest.
+    pub owed: usize,
+    /// The borrower's turns left until the loan must be repaid, if it has a deadline.
+    pub turns_left: Option<usize>,
+}
+
+fn amount(a: usize) -> N {
+    N::Bold(vec![N::text(format!("${}", a))])
+}
+
+impl Game {
+    /// Loans can be taken and repaid at the start of a player's turn or while buying shares.
+    fn loan_phase(&self, player: usize) -> bool {
+        match self.phase {
+            Phase::Play(p) | Phase::Buy { player: p, .. } => p == player,
+            _ => false,
+        }
+    }
+
+    pub fn can_borrow(&self, player: usize) -> bool {
+        match self.options.loans {
+            Some(terms) => {
+                self.loan_phase(player) && self.players[player].loans.len() < terms.limit
+            }
+            None => false,
+        }
+    }
+
+    pub fn can_repay(&self, player: usize) -> bool {
+        self.loan_phase(player) && !self.players[player].loans.is_empty()
+    }
+
+    pub fn handle_borrow_command(
+        &mut self,
+        player: usize,
+    ) -> Result<(Vec<Log>, bool), AcquireError> {
+        self.ensure_not_finished()?;
+        self.ensure_player_turn(player)?;
+        let terms = match self.options.loans {
+            Some(terms) if self.loan_phase(player) => terms,
+            _ => return Err(AcquireError::WrongPhase(Action::Borrow)),
+        };
+        if self.players[player].loans.len() >= terms.limit {
+            return Err(AcquireError::LoanLimit { limit: terms.limit });
+        }
+        if let Some(balance) = self.bank {
+            if balance < terms.amount {
+                return Err(AcquireError::BankCantLend { balance });
+            }
+            self.bank = Some(balance - terms.amount);
+        }
+        let interest = terms.amount * terms.interest / 100;
+        let owed = terms.amount + interest;
+        self.players[player].money += terms.amount;
+        self.players[player].loans.push(Loan {
+            owed,
+            turns_left: terms.term,
+        });
+        self.players[player].stats.loans += 1;
+        self.players[player].stats.loan_interest += interest;
+        Ok((
+            self.public_logs(|l| {
+                l.fill(
+                    Msg::Borrowed,
+                    vec![N::Player(player), amount(terms.amount), amount(owed)],
+                )
+            }),
+            true,
+        ))
+    }
+
+    /// Repay a player's oldest loan in full.
+    pub fn handle_repay_command(
+        &mut self,
+        player: usize,
+    ) -> Result<(Vec<Log>, bool), AcquireError> {
+        self.ensure_not_finished()?;
+        self.ensure_player_turn(player)?;
+        if !self.can_repay(player) {
+            return Err(AcquireError::WrongPhase(Action::Repay));
+        }
+        let owed = self.players[player].loans[0].owed;
+        let cash = self.players[player].money;
+        if owed > cash {
+            return Err(AcquireError::InsufficientFunds { cost: owed, cash });
+        }
+        self.players[player].loans.remove(0);
+        self.players[player].money -= owed;
+        self.bank_receive(owed);
+        Ok((
+            self.public_logs(|l| l.fill(Msg::Repaid, vec![N::Player(player), amount(owed)])),
+            true,
+        ))
+    }
+
+    /// Pay as much as possible towards loans, leaving anything which couldn't be paid owing.
+    /// Returns the amount paid.
+    fn pay_loans(&mut self, player: usize, due: &[usize]) -> usize {
+        let mut paid = 0;
+        for i in due {
+            let pay = self.players[player].money.min(self.players[player].loans[*i].owed);
+            self.players[player].money -= pay;
+            self.players[player].loans[*i].owed -= pay;
+            paid += pay;
+        }
+        self.players[player].loans.retain(|loan| loan.owed > 0);
+        self.bank_receive(paid);
+        paid
+    }
+
+    /// Count down a player's loans at the start of their turn, repaying any which are due.
+    pub(crate) fn loans_due(&mut self, player: usize) -> Vec<Log> {
+        let mut due: Vec<usize> = vec![];
+        for (i, loan) in self.players[player].loans.iter_mut().enumerate() {
+            if let Some(ref mut turns_left) = loan.turns_left {
+                *turns_left = turns_left.saturating_sub(1);
+                if *turns_left == 0 {
+                    due.push(i);
+                }
+            }
+        }
+        if due.is_empty() {
+            return vec![];
+        }
+        let paid = self.pay_loans(player, &due);
+        let still_owed = self.players[player].debt();
+        // Falling short of what's due gives away the player's cash.
+        self.redacted_logs(&[player], !self.money_visible(), |l, shown| {
+            l.fill(
+                Msg::LoanDue,
+                vec![
+                    N::Player(player),
+                    if shown { amount(paid) } else { redacted() },
+                    if shown { amount(still_owed) } else { redacted() },
+                ],
+            )
+        })
+    }
+
+    /// Repay all loans from players' cash at the end of the game. Debt which can't be covered
+    /// stays owing and counts against the player's score.
+    pub(crate) fn settle_loans(&mut self) -> Vec<Log> {
+        let mut logs: Vec<Log> = vec![];
+        for player in 0..self.players.len() {
+            let debt = self.players[player].debt();
+            if debt == 0 {
+                continue;
+            }
+            let due: Vec<usize> = (0..self.players[player].loans.len()).collect();
+            let paid = self.pay_loans(player, &due);
+            logs.extend(self.public_logs(|l| {
+                l.fill(
+                    Msg::LoansSettled,
+                    vec![N::Player(player), amount(paid), amount(debt)],
+                )
+            }));
+        }
+        logs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use brdgme_game::Gamer;
+
+    use crate::errors::AcquireError;
+    use crate::options::LoanTerms;
+    use crate::{Game, STARTING_MONEY};
+
+    #[test]
+    fn loans_work() {
+        let players = vec!["mick".to_string(), "steve".to_string()];
+        let mut g: Game = "...0
+                           ...1"
+            .into();
+        g.options.loans = Some(LoanTerms::default());
+        g.command(0, "borrow", &players)
+            .expect("expected 'borrow' to work");
+        assert_eq!(STARTING_MONEY + 5000, g.players[0].money);
+        assert_eq!(5500, g.players[0].debt());
+        g.command(0, "borrow", &players)
+            .expect("expected 'borrow' to work");
+        assert_eq!(
+            Err(AcquireError::LoanLimit { limit: 2 }),
+            g.handle_borrow_command(0).map(|_| ())
+        );
+        g.command(0, "repay", &players)
+            .expect("expected 'repay' to work");
+        assert_eq!(STARTING_MONEY + 4500, g.players[0].money);
+        g.settle_loans();
+        assert_eq!(STARTING_MONEY - 1000, g.players[0].money);
+        assert_eq!(0, g.players[0].debt());
+    }
+}
@@ -0,0 +1,235 @@
+use serde_derive::{Serialize, Deserialize};
+
+use crate::corp::Corp;
+use crate::PubState;
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Position {
+    Major,
+    Minor,
+    Outside,
+}
+
+/// A player's place in the shareholder race for a single corporation.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Standing {
+    pub player: usize,
+    pub shares: usize,
+    pub position: Position,
+    /// Whether the position is shared with other players.
+    pub tied: bool,
+    /// Shares needed to become the sole major shareholder, 0 if already there.
+    pub to_major: usize,
+    /// Shares needed to hold at least the sole minor position, 0 if already there.
+    pub to_minor: usize,
+    /// Shares needed from the bank so the current position can't be matched by any other player,
+    /// ignoring trades. `None` if the player has no position or it can't be locked in.
+    pub to_secure: Option<usize>,
+}
+
+/// The state of the shareholder race for a corporation, as bonuses would be paid right now.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MajorityRace {
+    pub corp: Corp,
+    pub major: Vec<usize>,
+    pub minor: Vec<usize>,
+    pub bank_shares: usize,
+    pub standings: Vec<Standing>,
+}
+
+/// Split holders into the major and minor shareholders. `holdings` are pairs of holder and share
+/// count, holders without shares are ignored. If multiple holders share the major position there
+/// is no minor position as they share the minor bonus.
+pub fn major_minor(holdings: &[(usize, usize)]) -> (Vec<usize>, Vec<usize>) {
+    let mut major: Vec<usize> = vec![];
+    let mut major_count: usize = 0;
+    let mut minor: Vec<usize> = vec![];
+    let mut minor_count: usize = 0;
+    for &(holder, shares) in holdings {
+        if shares == 0 {
+            continue;
+        }
+        if shares > major_count {
+            minor = major;
+            minor_count = major_count;
+            major = vec![];
+            major_count = shares;
+        }
+        if shares == major_count {
+            major.push(holder);
+        } else {
+            if shares > minor_count {
+                minor = vec![];
+                minor_count = shares;
+            }
+            if shares == minor_count {
+                minor.push(holder);
+            }
+        }
+    }
+    if major.len() > 1 {
+        // If there are multiple majors, they share the minor bonus too
+        minor = vec![];
+    }
+    (major, minor)
+}
+
+/// Shares needed to go from `own` to strictly more than `target`.
+fn to_pass(own: usize, target: usize) -> usize {
+    (target + 1).saturating_sub(own)
+}
+
+/// Shares needed from the bank so `own` stays strictly ahead of `rival` even if the rival bought
+/// everything left in the bank.
+fn to_lock(own: usize, rival: usize, bank: usize) -> Option<usize> {
+    if own > rival + bank {
+        return Some(0);
+    }
+    let needed = (rival + bank - own) / 2 + 1;
+    if needed > bank {
+        None
+    } else {
+        Some(needed)
+    }
+}
+
+impl MajorityRace {
+    /// `holdings` is the number of shares held by each player.
+    pub fn new(corp: Corp, holdings: &[usize], bank_shares: usize) -> Self {
+        let (major, minor) = major_minor(&holdings
+            .iter()
+            .cloned()
+            .enumerate()
+            .collect::<Vec<(usize, usize)>>());
+        let standings = holdings
+            .iter()
+            .enumerate()
+            .map(|(player, &shares)| {
+                let mut others: Vec<usize> = holdings
+                    .iter()
+                    .enumerate()
+                    .filter(|&(p, _)| p != player)
+                    .map(|(_, &s)| s)
+                    .collect();
+                others.sort_by(|a, b| b.cmp(a));
+                let first = others.get(0).cloned().unwrap_or(0);
+                let second = others.get(1).cloned().unwrap_or(0);
+                let position = if major.contains(&player) {
+                    Position::Major
+                } else if minor.contains(&player) {
+                    Position::Minor
+                } else {
+                    Position::Outside
+                };
+                Standing {
+                    player,
+                    shares,
+                    position,
+                    tied: match position {
+                        Position::Major => major.len() > 1,
+                        Position::Minor => minor.len() > 1,
+                        Position::Outside => false,
+                    },
+                    to_major: to_pass(shares, first),
+                    to_minor: to_pass(shares, second),
+                    to_secure: match position {
+                        Position::Major => to_lock(shares, first, bank_shares),
+                        Position::Minor => to_lock(shares, second, bank_shares),
+                        Position::Outside => None,
+                    },
+                }
+            })
+            .collect();
+        MajorityRace {
+            corp,
+            major,
+            minor,
+            bank_shares,
+            standings,
+        }
+    }
+
+    /// The number of shares held by players in the position, 0 if nobody holds it.
+    pub fn position_shares(&self, position: Position) -> usize {
+        self.standings
+            .iter()
+            .find(|s| s.position == position)
+            .map(|s| s.shares)
+            .unwrap_or(0)
+    }
+
+    /// How many shares the holders of a position are ahead of the best placed player below them.
+    pub fn lead(&self, position: Position) -> usize {
+        let below = self.standings
+            .iter()
+            .filter(|s| match position {
+                Position::Major => s.position != Position::Major,
+                _ => s.position == Position::Outside,
+            })
+            .map(|s| s.shares)
+            .max()
+            .unwrap_or(0);
+        self.position_shares(position).saturating_sub(below)
+    }
+}
+
+impl PubState {
+    /// The shareholder races for every corporation on the board. The dummy player in 2 player
+    /// games is ignored as its shares aren't known until bonuses are paid. There are no races
+    /// if any player's shares are hidden.
+    pub fn majority_races(&self) -> Vec<MajorityRace> {
+        if self.players.iter().any(|p| p.shares.is_none()) {
+            return vec![];
+        }
+        Corp::iter()
+            .filter(|c| self.board.corp_size(c) > 0)
+            .map(|c| {
+                MajorityRace::new(
+                    *c,
+                    &self.players
+                        .iter()
+                        .map(|p| {
+                            p.shares
+                                .as_ref()
+                                .and_then(|s| s.get(c).cloned())
+                                .unwrap_or(0)
+                        })
+                        .collect::<Vec<usize>>(),
+                    self.shares.get(c).cloned().unwrap_or(0),
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn major_minor_works() {
+        assert_eq!(
+            (vec![1], vec![0, 2]),
+            major_minor(&[(0, 3), (1, 5), (2, 3), (3, 0)])
+        );
+        assert_eq!((vec![0, 1], vec![]), major_minor(&[(0, 4), (1, 4), (2, 2)]));
+    }
+
+    #[test]
+    fn majority_race_works() {
+        let race = MajorityRace::new(Corp::Tower, &[6, 3, 0], 16);
+        assert_eq!(vec![0], race.major);
+        assert_eq!(vec![1], race.minor);
+        assert_eq!(3, race.lead(Position::Major));
+        assert_eq!(3, race.lead(Position::Minor));
+        let leader = &race.standings[0];
+        assert_eq!(0, leader.to_major);
+        // 6 + 7 = 13 can't be caught by 3 + 9 = 12.
+        assert_eq!(Some(7), leader.to_secure);
+        let last = &race.standings[2];
+        assert_eq!(Position::Outside, last.position);
+        assert_eq!(7, last.to_major);
+        assert_eq!(4, last.to_minor);
+        assert_eq!(None, last.to_secure);
+    }
+}
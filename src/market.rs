@@ -0,0 +1,185 @@
+use serde_derive::{Serialize, Deserialize};
+
+use brdgme_game::{Log, Stat};
+use brdgme_markup::Node as N;
+
+use std::collections::HashMap;
+
+use crate::corp::Corp;
+use crate::i18n::Msg;
+use crate::prices::PriceTable;
+use crate::Game;
+
+/// How share prices have moved with supply and demand, when using the market variant.
+#[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Market {
+    /// How far each corporation's price has moved from the price table.
+    pub shift: HashMap<Corp, isize>,
+    /// Net shares bought in the current round.
+    pub demand: HashMap<Corp, isize>,
+    /// Each corporation's share price at the end of every round, zero when it wasn't on the
+    /// board.
+    pub history: HashMap<Corp, Vec<usize>>,
+}
+
+impl Market {
+    /// A price from the price table moved by the market, staying within the brackets either
+    /// side of the corporation's size.
+    pub fn price(&self, prices: &PriceTable, corp: &Corp, size: usize) -> usize {
+        let (min, max) = prices.bounds(corp, size);
+        let shift = self.shift.get(corp).cloned().unwrap_or(0);
+        let shifted = prices.value(corp, size) as isize + shift;
+        shifted.max(min as isize).min(max as isize) as usize
+    }
+
+    /// The price at the end of the last round, to compare the current price against.
+    pub fn last_price(&self, corp: &Corp) -> Option<usize> {
+        self.history.get(corp).and_then(|h| h.last().cloned())
+    }
+
+    /// Price history as stats, one list per corporation.
+    pub fn to_brdgme_stats(&self, names: &[(Corp, String)]) -> HashMap<String, Stat> {
+        names
+            .iter()
+            .map(|&(ref corp, ref name)| {
+                (
+                    format!("{} price history", name),
+                    Stat::List(
+                        self.history
+                            .get(corp)
+                            .map(|h| h.iter().map(|p| p.to_string()).collect())
+                            .unwrap_or_else(|| vec![]),
+                    ),
+                )
+            })
+            .collect()
+    }
+}
+
+impl Game {
+    /// Shares bought push the price up at the end of the round.
+    pub(crate) fn market_buy(&mut self, corp: &Corp, n: usize) {
+        if let Some(ref mut market) = self.market {
+            *market.demand.entry(*corp).or_insert(0) += n as isize;
+        }
+    }
+
+    /// Shares sold in a merger push the price down straight away, so later sellers get less.
+    pub(crate) fn market_sell(&mut self, corp: &Corp, n: usize) {
+        let step = match self.options.market {
+            Some(rule) => rule.step as isize,
+            None => return,
+        };
+        if let Some(ref mut market) = self.market {
+            *market.shift.entry(*corp).or_insert(0) -= n as isize * step;
+        }
+        self.clamp_shift(corp);
+    }
+
+    /// Newly founded corporations start at their price table value.
+    pub(crate) fn market_reset(&mut self, corp: &Corp) {
+        if let Some(ref mut market) = self.market {
+            market.shift.remove(corp);
+            market.demand.remove(corp);
+        }
+    }
+
+    /// Stop the shift building up beyond what the price can move, so it can recover straight
+    /// away when demand changes.
+    fn clamp_shift(&mut self, corp: &Corp) {
+        let size = self.board.corp_size(corp);
+        let value = self.options.prices.value(corp, size) as isize;
+        let price = self.share_price(corp) as isize;
+        if let Some(ref mut market) = self.market {
+            market.shift.insert(*corp, price - value);
+        }
+    }
+
+    /// Whether moving from one player's turn to the next starts a new round.
+    pub(crate) fn round_ends(&self, current: usize, next: usize) -> bool {
+        let players = self.players.len();
+        let offset = |p: usize| (p + players - self.start_player) % players;
+        offset(next) <= offset(current)
+    }
+
+    /// Apply the round's demand to prices and record them in the history.
+    pub(crate) fn end_market_round(&mut self) -> Vec<Log> {
+        let step = match (self.options.market, self.market.is_some()) {
+            (Some(rule), true) => rule.step as isize,
+            _ => return vec![],
+        };
+        let corps = self.options.roster.corps();
+        let mut moves: Vec<(Corp, usize, usize)> = vec![];
+        for corp in &corps {
+            let before = self.share_price(corp);
+            if let Some(ref mut market) = self.market {
+                let demand = market.demand.remove(corp).unwrap_or(0);
+                *market.shift.entry(*corp).or_insert(0) += demand * step;
+            }
+            self.clamp_shift(corp);
+            let after = self.share_price(corp);
+            let on_board = self.board.corp_size(corp) > 0;
+            if let Some(ref mut market) = self.market {
+                market
+                    .history
+                    .entry(*corp)
+                    .or_insert_with(|| vec![])
+                    .push(if on_board { after } else { 0 });
+            }
+            if on_board && after != before {
+                moves.push((*corp, before, after));
+            }
+        }
+        moves
+            .iter()
+            .flat_map(|&(corp, before, after)| {
+                self.public_logs(|l| {
+                    l.fill(
+                        Msg::MarketMoved,
+                        vec![
+                            self.render_corp(&corp),
+                            N::text(format!("${}", before)),
+                            N::Bold(vec![N::text(format!("${}", after))]),
+                        ],
+                    )
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use brdgme_game::Gamer;
+
+    use super::Market;
+    use crate::corp::Corp;
+    use crate::options::MarketRule;
+    use crate::Game;
+
+    #[test]
+    fn market_moves_within_brackets() {
+        let players = vec!["mick".to_string(), "steve".to_string()];
+        let mut g: Game = "AAA0
+                           ....1"
+            .into();
+        g.options.market = Some(MarketRule { step: 50 });
+        g.market = Some(Market::default());
+        let value = g.options.prices.value(&Corp::American, 3);
+        g.market_buy(&Corp::American, 3);
+        g.end_market_round();
+        assert_eq!(value + 100, g.share_price(&Corp::American));
+        assert_eq!(
+            Some(&vec![value + 100]),
+            g.market.as_ref().and_then(|m| m.history.get(&Corp::American))
+        );
+        g.market_sell(&Corp::American, 10);
+        assert_eq!(
+            g.options.prices.value(&Corp::American, 0),
+            g.share_price(&Corp::American)
+        );
+        g.command(0, "play a4", &players)
+            .expect("expected 'play a4' to work");
+        assert!(g.share_price(&Corp::American) >= value);
+    }
+}
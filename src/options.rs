@@ -0,0 +1,286 @@
+use serde_derive::{Serialize, Deserialize};
+
+use crate::board::Geometry;
+use crate::i18n::Locale;
+use crate::prices::PriceTable;
+use crate::roster::Roster;
+
+/// Rule options chosen when the game is created.
+#[derive(Default, PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Options {
+    /// Seconds a player has to act before a default action is taken for them.
+    pub turn_time_limit: Option<u64>,
+    /// What happens to the seat of a player who resigns.
+    pub resign_policy: ResignPolicy,
+    /// The locale players start with and spectators see.
+    pub locale: Locale,
+    /// How tiles which can never be played are replaced.
+    pub dead_tile_rule: DeadTileRule,
+    /// What happens when a player has no playable tiles at the start of their turn.
+    pub unplayable_hand_rule: UnplayableHandRule,
+    /// Which parts of players' holdings everyone can see.
+    pub visibility: Visibility,
+    /// Seats grouped into partnerships, empty when playing without teams.
+    pub teams: Vec<Vec<usize>>,
+    /// Whether partners' shares are combined when paying shareholder bonuses.
+    pub team_majority: TeamMajority,
+    /// Tiebreaks applied in order to players finishing with the same cash. Players still tied
+    /// after the chain share their placing.
+    pub tiebreaks: Vec<Tiebreak>,
+    /// Whether the end of the game is triggered by players or automatically.
+    pub end_trigger: EndTrigger,
+    /// The chart share prices and shareholder bonuses are read from.
+    pub prices: PriceTable,
+    /// The corporations in play and how they are named. Their tiers come from `prices`.
+    pub roster: Roster,
+    /// The shape of the board, which tiles are dealt from.
+    pub geometry: Geometry,
+    /// The money supply, unlimited unless a bank limit is set.
+    pub bank: Option<BankLimit>,
+    /// The terms players can borrow from the bank on, if loans are allowed.
+    pub loans: Option<LoanTerms>,
+    /// Auctions for the first shares of newly founded corporations, if used.
+    pub auction: Option<AuctionRule>,
+    /// How share prices move with supply and demand, if they do.
+    pub market: Option<MarketRule>,
+}
+
+impl Options {
+    /// The hidden cash variant, where players' cash is only known to themselves until the end of
+    /// the game.
+    pub fn hidden_cash() -> Self {
+        Options {
+            visibility: Visibility {
+                money: false,
+                ..Visibility::default()
+            },
+            ..Options::default()
+        }
+    }
+
+    /// The corporation names and tiers of the modern editions.
+    pub fn modern() -> Self {
+        Options {
+            prices: PriceTable::hasbro(),
+            roster: Roster::modern(),
+            ..Options::default()
+        }
+    }
+
+    /// The banking crisis house rule, where a small money supply is shared out pro rata once the
+    /// bank can't pay in full.
+    pub fn banking_crisis() -> Self {
+        Options {
+            bank: Some(BankLimit {
+                total: 60_000,
+                shortfall: Shortfall::ProRata,
+            }),
+            ..Options::default()
+        }
+    }
+
+    /// A smaller board for tighter two player games.
+    pub fn small_board() -> Self {
+        Options {
+            geometry: Geometry::small(),
+            ..Options::default()
+        }
+    }
+}
+
+/// A fixed amount of money in the game, held by the bank and the players.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BankLimit {
+    /// All money in the game, including players' starting cash.
+    pub total: usize,
+    #[serde(default)]
+    pub shortfall: Shortfall,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MarketRule {
+    /// How far the price moves for each share of net demand.
+    pub step: usize,
+}
+
+impl Default for MarketRule {
+    fn default() -> Self {
+        MarketRule { step: 25 }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AuctionRule {
+    pub style: AuctionStyle,
+    /// How many shares are auctioned as a block.
+    pub shares: usize,
+}
+
+impl Default for AuctionRule {
+    fn default() -> Self {
+        AuctionRule {
+            style: AuctionStyle::default(),
+            shares: 3,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum AuctionStyle {
+    /// Every player makes one secret bid at the same time.
+    Sealed,
+    /// Players take turns raising the bid until all but one have passed.
+    Open,
+}
+
+impl Default for AuctionStyle {
+    fn default() -> Self {
+        AuctionStyle::Sealed
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LoanTerms {
+    /// The amount lent by each loan.
+    pub amount: usize,
+    /// Interest added to each loan when it is taken, as a percentage.
+    pub interest: usize,
+    /// The most loans a player can owe at once.
+    pub limit: usize,
+    /// The borrower's turns until a loan must be repaid, otherwise loans are repaid when the
+    /// game ends.
+    #[serde(default)]
+    pub term: Option<usize>,
+}
+
+impl Default for LoanTerms {
+    fn default() -> Self {
+        LoanTerms {
+            amount: 5000,
+            interest: 10,
+            limit: 2,
+            term: None,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Shortfall {
+    /// Payments are made in order in full until the bank runs out.
+    Capped,
+    /// Every payment is reduced by the same proportion.
+    ProRata,
+}
+
+impl Default for Shortfall {
+    fn default() -> Self {
+        Shortfall::Capped
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum TeamMajority {
+    /// Partners' shares count together, with a team's bonus split between its members.
+    Combined,
+    /// Each player's shares count separately, as in a game without teams.
+    Separate,
+}
+
+impl Default for TeamMajority {
+    fn default() -> Self {
+        TeamMajority::Combined
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum EndTrigger {
+    /// The player whose turn it is must use `end` to trigger the end of the game.
+    Manual,
+    /// The game ends as soon as a turn finishes with the end condition met.
+    Automatic,
+    /// As manual, but players who can end the game are told so along with the projected result.
+    Advisory,
+}
+
+impl Default for EndTrigger {
+    fn default() -> Self {
+        EndTrigger::Manual
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Tiebreak {
+    /// Most shares held before they were sold off at the end of the game.
+    MostShares,
+    /// Most major shareholder bonuses earned.
+    MostMajorBonuses,
+    /// Earliest in turn order.
+    TurnOrder,
+}
+
+/// Which of a player's holdings are visible to other players. Everything is revealed once the
+/// game is finished.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Visibility {
+    pub tiles: bool,
+    pub money: bool,
+    pub shares: bool,
+}
+
+impl Default for Visibility {
+    fn default() -> Self {
+        Visibility {
+            tiles: false,
+            money: true,
+            shares: true,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ResignPolicy {
+    /// A bot takes over the seat and plays out its turns.
+    Bot,
+    /// The seat's shares are sold to the bank and its tiles are discarded.
+    Liquidate,
+}
+
+impl Default for ResignPolicy {
+    fn default() -> Self {
+        ResignPolicy::Liquidate
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum DeadTileRule {
+    /// Dead tiles are discarded automatically when replacement tiles are drawn.
+    AutoDiscard,
+    /// Players may exchange one dead tile per turn using `discard`, drawing a replacement
+    /// immediately.
+    ExchangeOne,
+    /// Players choose when to discard dead tiles using `discard`, drawing replacements at the end
+    /// of their turn.
+    PlayerChoice,
+}
+
+impl Default for DeadTileRule {
+    fn default() -> Self {
+        DeadTileRule::AutoDiscard
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum UnplayableHandRule {
+    /// The hand is discarded and a new one drawn.
+    Redraw,
+    /// The hand is announced and kept, and the player skips placing a tile.
+    Announce,
+}
+
+impl Default for UnplayableHandRule {
+    fn default() -> Self {
+        UnplayableHandRule::Redraw
+    }
+}
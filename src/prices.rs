@@ -0,0 +1,188 @@
+use serde_derive::{Serialize, Deserialize};
+
+use std::collections::HashMap;
+
+use crate::corp::Corp;
+use crate::errors::AcquireError;
+use crate::{Game, PubState};
+
+/// An amount added to a corporation's share price once it reaches a size.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Bracket {
+    pub size: usize,
+    pub add: usize,
+}
+
+/// The chart share prices and shareholder bonuses are read from. Custom tables can be
+/// deserialised from a definition and checked with `validate`.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct PriceTable {
+    /// The share price of each corporation at size 2.
+    pub base: HashMap<Corp, usize>,
+    /// Brackets in ascending order of size.
+    pub brackets: Vec<Bracket>,
+    pub minor_mult: usize,
+    pub major_mult: usize,
+}
+
+impl Default for PriceTable {
+    fn default() -> Self {
+        PriceTable::classic()
+    }
+}
+
+fn tiers(cheap: &[Corp], medium: &[Corp], expensive: &[Corp]) -> HashMap<Corp, usize> {
+    let mut base: HashMap<Corp, usize> = HashMap::new();
+    for (corps, value) in &[(cheap, 200), (medium, 300), (expensive, 400)] {
+        for corp in corps.iter() {
+            base.insert(*corp, *value);
+        }
+    }
+    base
+}
+
+fn standard_brackets() -> Vec<Bracket> {
+    [
+        (3, 100),
+        (4, 200),
+        (5, 300),
+        (6, 400),
+        (11, 500),
+        (21, 600),
+        (31, 700),
+        (41, 800),
+    ].iter()
+        .map(|&(size, add)| Bracket { size, add })
+        .collect()
+}
+
+impl PriceTable {
+    /// The chart this game has always used.
+    pub fn classic() -> Self {
+        PriceTable {
+            base: tiers(
+                &[Corp::Worldwide, Corp::Sackson],
+                &[Corp::Festival, Corp::Imperial, Corp::American],
+                &[Corp::Continental, Corp::Tower],
+            ),
+            brackets: standard_brackets(),
+            minor_mult: 5,
+            major_mult: 10,
+        }
+    }
+
+    /// The tiers from the Hasbro 1999 and 2008 editions, with Sackson in Luxor's place.
+    pub fn hasbro() -> Self {
+        PriceTable {
+            base: tiers(
+                &[Corp::Tower, Corp::Sackson],
+                &[Corp::American, Corp::Worldwide, Corp::Festival],
+                &[Corp::Imperial, Corp::Continental],
+            ),
+            ..PriceTable::classic()
+        }
+    }
+
+    /// Check every corporation in play has a price and brackets are in ascending order.
+    pub fn validate(&self, corps: &[Corp]) -> Result<(), AcquireError> {
+        if let Some(corp) = corps.iter().find(|c| !self.base.contains_key(c)) {
+            return Err(AcquireError::NoBasePrice(*corp));
+        }
+        if self.brackets.windows(2).any(|w| w[0].size >= w[1].size) {
+            return Err(AcquireError::BracketsOutOfOrder);
+        }
+        Ok(())
+    }
+
+    pub fn value(&self, corp: &Corp, size: usize) -> usize {
+        self.base.get(corp).cloned().unwrap_or(0)
+            + self.brackets
+                .iter()
+                .rev()
+                .find(|b| size >= b.size)
+                .map(|b| b.add)
+                .unwrap_or(0)
+    }
+
+    pub fn minor_bonus(&self, corp: &Corp, size: usize) -> usize {
+        self.value(corp, size) * self.minor_mult
+    }
+
+    pub fn major_bonus(&self, corp: &Corp, size: usize) -> usize {
+        self.value(corp, size) * self.major_mult
+    }
+
+    /// The values of the brackets either side of a size, which market prices stay within.
+    pub fn bounds(&self, corp: &Corp, size: usize) -> (usize, usize) {
+        let lower = self.brackets
+            .iter()
+            .rev()
+            .map(|b| b.size)
+            .filter(|s| *s <= size)
+            .nth(1)
+            .unwrap_or(0);
+        let upper = self.next_bracket(size).unwrap_or(size);
+        (self.value(corp, lower), self.value(corp, upper))
+    }
+
+    /// The size a corporation of the given size must reach for its value to next increase, if it
+    /// can increase any further.
+    pub fn next_bracket(&self, size: usize) -> Option<usize> {
+        self.brackets.iter().map(|b| b.size).find(|s| *s > size)
+    }
+}
+
+impl Game {
+    /// The current share price of a corporation, including any movement in the market.
+    pub fn share_price(&self, corp: &Corp) -> usize {
+        let size = self.board.corp_size(corp);
+        match self.market {
+            Some(ref market) => market.price(&self.options.prices, corp, size),
+            None => self.options.prices.value(corp, size),
+        }
+    }
+}
+
+impl PubState {
+    /// The current share price of a corporation, including any movement in the market.
+    pub fn share_price(&self, corp: &Corp) -> usize {
+        let size = self.board.corp_size(corp);
+        match self.market {
+            Some(ref market) => market.price(&self.prices, corp, size),
+            None => self.prices.value(corp, size),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PriceTable;
+    use crate::corp::{Corp, CORPS};
+    use crate::errors::AcquireError;
+    use crate::roster::Roster;
+
+    #[test]
+    fn classic_values_work() {
+        let prices = PriceTable::classic();
+        assert!(prices.validate(&CORPS).is_ok());
+        assert_eq!(200, prices.value(&Corp::Worldwide, 2));
+        assert_eq!(700, prices.value(&Corp::American, 6));
+        assert_eq!(1200, prices.value(&Corp::Tower, 50));
+        assert_eq!(3500, prices.minor_bonus(&Corp::American, 6));
+        assert_eq!(Some(11), prices.next_bracket(6));
+        assert_eq!(None, prices.next_bracket(41));
+        assert_eq!((500, 700), prices.bounds(&Corp::American, 5));
+        assert_eq!(200, PriceTable::hasbro().value(&Corp::Tower, 2));
+    }
+
+    #[test]
+    fn validate_only_needs_prices_for_the_roster() {
+        let mut prices = PriceTable::classic();
+        prices.base.remove(&Corp::Sackson);
+        assert_eq!(
+            Err(AcquireError::NoBasePrice(Corp::Sackson)),
+            prices.validate(&CORPS)
+        );
+        assert!(prices.validate(&Roster::quick().corps()).is_ok());
+    }
+}
@@ -0,0 +1,230 @@
+use brdgme_game::Log;
+use brdgme_markup::Node as N;
+
+use crate::board::{Loc, TileEffect};
+use crate::corp::Corp;
+use crate::i18n::Msg;
+use crate::render::comma_list;
+use crate::Game;
+
+impl Game {
+    fn corp_list(&self, corps: &[Corp]) -> N {
+        comma_list(corps.iter().map(|c| self.render_corp(c)).collect())
+    }
+
+    /// Shareholders of a corporation visible to the viewer with their share counts, largest
+    /// holding first.
+    fn shareholders(&self, viewer: usize, corp: &Corp) -> Vec<(usize, usize)> {
+        let mut holders: Vec<(usize, usize)> = self.players
+            .iter()
+            .enumerate()
+            .filter(|&(p, _)| self.is_teammate(viewer, p) || self.shares_visible())
+            .map(|(p, state)| (p, state.shares.get(corp).cloned().unwrap_or(0)))
+            .filter(|&(_, shares)| shares > 0)
+            .collect();
+        holders.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        holders
+    }
+
+    pub fn info_query(&self, player: usize, corp: &Corp) -> Vec<Log> {
+        let l = self.locale(player);
+        let size = self.board.corp_size(corp);
+        let mut logs: Vec<Log> = vec![];
+        if size == 0 {
+            logs.push(Log::private(
+                l.fill(
+                    Msg::InfoNotOnBoard,
+                    vec![
+                        self.render_corp(&corp),
+                        N::Bold(vec![N::text(format!(
+                            "${}",
+                            self.options.prices.value(corp, 2)
+                        ))]),
+                    ],
+                ),
+                vec![player],
+            ));
+        } else {
+            logs.push(Log::private(
+                l.fill(
+                    if self.board.corp_is_safe(corp) {
+                        Msg::InfoSafe
+                    } else {
+                        Msg::InfoNotSafe
+                    },
+                    vec![
+                        self.render_corp(&corp),
+                        N::Bold(vec![N::text(format!("{}", size))]),
+                        N::Bold(vec![N::text(format!("${}", self.share_price(corp)))]),
+                    ],
+                ),
+                vec![player],
+            ));
+            logs.push(Log::private(
+                match self.options.prices.next_bracket(size) {
+                    Some(next) => l.fill(
+                        Msg::InfoNextBracket,
+                        vec![
+                            N::Bold(vec![N::text(format!(
+                                "${}",
+                                self.options.prices.value(corp, next)
+                            ))]),
+                            N::Bold(vec![N::text(format!("{}", next))]),
+                        ],
+                    ),
+                    None => vec![N::text(l.template(Msg::InfoMaxValue))],
+                },
+                vec![player],
+            ));
+        }
+        logs.push(Log::private(
+            l.fill(
+                Msg::InfoBankShares,
+                vec![N::Bold(vec![N::text(format!(
+                    "{}",
+                    self.shares.get(corp).cloned().unwrap_or(0)
+                ))])],
+            ),
+            vec![player],
+        ));
+        let holders = self.shareholders(player, corp);
+        logs.push(Log::private(
+            if holders.is_empty() {
+                vec![N::text(l.template(Msg::InfoNoHolders))]
+            } else {
+                l.fill(
+                    Msg::InfoHolders,
+                    vec![comma_list(
+                        holders
+                            .iter()
+                            .map(|&(p, shares)| {
+                                N::Group(vec![
+                                    N::Player(p),
+                                    N::text(" ("),
+                                    N::Bold(vec![N::text(format!("{}", shares))]),
+                                    N::text(")"),
+                                ])
+                            })
+                            .collect(),
+                    )],
+                )
+            },
+            vec![player],
+        ));
+        logs
+    }
+
+    pub fn tile_query(&self, player: usize, loc: &Loc) -> Vec<Log> {
+        let l = self.locale(player);
+        let content = match self.board.tile_effect(loc) {
+            TileEffect::Occupied => l.fill(Msg::TileOccupied, vec![loc.render()]),
+            TileEffect::MergesSafeCorps(corps) => {
+                l.fill(Msg::TileSafeMerge, vec![loc.render(), self.corp_list(&corps)])
+            }
+            TileEffect::NoCorpAvailable => l.fill(Msg::TileNoCorp, vec![loc.render()]),
+            TileEffect::Unincorporated => l.fill(Msg::TileUnincorporated, vec![loc.render()]),
+            TileEffect::Found => l.fill(Msg::TileFound, vec![loc.render()]),
+            TileEffect::Grow(c) => l.fill(
+                Msg::TileGrow,
+                vec![
+                    loc.render(),
+                    self.render_corp(&c),
+                    N::Bold(vec![N::text(format!("{}", self.board.corp_size(&c) + 1))]),
+                ],
+            ),
+            TileEffect::Merge { from, into } => {
+                if into.len() > 1 {
+                    l.fill(Msg::TileMergeChoice, vec![loc.render(), self.corp_list(&into)])
+                } else {
+                    l.fill(
+                        Msg::TileMerge,
+                        vec![loc.render(), self.corp_list(&from), self.corp_list(&into)],
+                    )
+                }
+            }
+        };
+        vec![Log::private(content, vec![player])]
+    }
+
+    pub fn majority_query(&self, player: usize, corp: &Corp) -> Vec<Log> {
+        let l = self.locale(player);
+        if !self.shares_visible() {
+            return vec![Log::private(
+                vec![N::text(l.template(Msg::HoldingsHidden))],
+                vec![player],
+            )];
+        }
+        let holders = self.shareholders(player, corp);
+        let content = match holders.len() {
+            0 => l.fill(Msg::MajorityNobody, vec![self.render_corp(&corp)]),
+            1 => l.fill(
+                Msg::MajorityOnly,
+                vec![
+                    N::Player(holders[0].0),
+                    self.render_corp(&corp),
+                    N::Bold(vec![N::text(format!("{}", holders[0].1))]),
+                ],
+            ),
+            _ => {
+                let (leader, lead_shares) = holders[0];
+                let (second, second_shares) = holders[1];
+                if lead_shares == second_shares {
+                    l.fill(
+                        Msg::MajorityTied,
+                        vec![
+                            comma_list(
+                                holders
+                                    .iter()
+                                    .filter(|&&(_, s)| s == lead_shares)
+                                    .map(|&(p, _)| N::Player(p))
+                                    .collect(),
+                            ),
+                            self.render_corp(&corp),
+                            N::Bold(vec![N::text(format!("{}", lead_shares))]),
+                        ],
+                    )
+                } else {
+                    l.fill(
+                        Msg::MajorityLeads,
+                        vec![
+                            N::Player(leader),
+                            self.render_corp(&corp),
+                            N::Bold(vec![N::text(format!("{}", lead_shares))]),
+                            N::Player(second),
+                            N::Bold(vec![N::text(format!("{}", lead_shares - second_shares))]),
+                        ],
+                    )
+                }
+            }
+        };
+        vec![Log::private(content, vec![player])]
+    }
+
+    pub fn deck_query(&self, player: usize) -> Vec<Log> {
+        let l = self.locale(player);
+        let unseen = self.unseen_tiles(player);
+        let mut logs = vec![Log::private(
+            l.fill(
+                Msg::DeckUnseen,
+                vec![
+                    N::Bold(vec![N::text(format!("{}", unseen.len()))]),
+                    comma_list(unseen.iter().map(|t| t.render()).collect()),
+                ],
+            ),
+            vec![player],
+        )];
+        if player < self.players.len() {
+            logs.push(Log::private(
+                l.fill(
+                    Msg::DeckFoundChance,
+                    vec![N::Bold(vec![N::text(format!(
+                        "{:.0}%",
+                        self.tile_knowledge(player).chance_draw_founding() * 100.0
+                    ))])],
+                ),
+                vec![player],
+            ));
+        }
+        logs
+    }
+}
@@ -4,8 +4,14 @@ use brdgme_color::*;
 
 use crate::PlayerState;
 use crate::PubState;
-use crate::board::{self, Board, Loc, Tile};
-use crate::corp::{Corp, GAME_END_SIZE, MAJOR_MULT, MINOR_MULT};
+use crate::board::{Board, Loc, Tile};
+use crate::corp::{Corp, GAME_END_SIZE};
+use crate::i18n::{Locale, Msg};
+use crate::majority::{MajorityRace, Position};
+use crate::report::EndGameReport;
+use crate::roster::Roster;
+use crate::seat::Seat;
+use crate::visibility::redacted;
 use crate::CanEnd;
 use crate::CanEndFalse;
 
@@ -44,70 +50,157 @@ static AVAILABLE_LOC_BG: Color = Color {
     b: 208,
 };
 
-fn render(pub_state: &PubState, player: Option<usize>, tiles: &[Loc]) -> Vec<N> {
-    vec![
-        N::Table(vec![
-            vec![(A::Center, vec![pub_state.board.render(tiles)])],
-            vec![],
-            vec![(A::Center, vec![pub_state.can_end().render_end_text()])],
-            vec![(A::Center, vec![pub_state.render_remaining_tiles_text()])],
-            vec![],
-            vec![(A::Center, vec![pub_state.corp_table()])],
-            vec![],
-            vec![(A::Center, vec![pub_state.player_table(player)])],
-        ]),
-    ]
+static PRICE_RISING_COLOR: Color = Color {
+    r: 56,
+    g: 142,
+    b: 60,
+};
+
+static PRICE_FALLING_COLOR: Color = Color {
+    r: 211,
+    g: 47,
+    b: 47,
+};
+
+fn render_bank_text(balance: usize, locale: Locale) -> N {
+    N::Fg(
+        GREY.into(),
+        locale.fill(
+            Msg::BankBalance,
+            vec![N::Bold(vec![N::text(format!("${}", balance))])],
+        ),
+    )
+}
+
+fn render(pub_state: &PubState, player: Option<usize>, tiles: &[Loc], locale: Locale) -> Vec<N> {
+    let mut rows = vec![
+        vec![
+            (
+                A::Center,
+                vec![
+                    pub_state
+                        .board
+                        .render(tiles, |c| pub_state.share_price(c), &pub_state.roster),
+                ],
+            ),
+        ],
+        vec![],
+        vec![(A::Center, vec![pub_state.can_end().render_end_text(locale)])],
+        vec![(A::Center, vec![pub_state.render_remaining_tiles_text(locale)])],
+    ];
+    if let Some(auction) = pub_state.render_auction_text(locale) {
+        rows.push(vec![(A::Center, vec![auction])]);
+    }
+    if let Some(balance) = pub_state.bank {
+        rows.push(vec![(A::Center, vec![render_bank_text(balance, locale)])]);
+    }
+    rows.extend(vec![
+        vec![],
+        vec![(A::Center, vec![pub_state.corp_table(locale)])],
+        vec![],
+        vec![(A::Center, vec![pub_state.player_table(player, locale)])],
+    ]);
+    if !pub_state.teams.is_empty() {
+        rows.push(vec![]);
+        rows.push(vec![(A::Center, vec![pub_state.team_table(locale)])]);
+    }
+    if let Some(ref report) = pub_state.end_report {
+        rows.push(vec![]);
+        rows.push(vec![(A::Center, vec![report.render(locale, &pub_state.roster)])]);
+    }
+    vec![N::Table(rows)]
 }
 
 impl Renderer for PubState {
     fn render(&self) -> Vec<N> {
-        render(self, None, &[])
+        render(self, None, &[], self.locale)
     }
 }
 
 impl Renderer for PlayerState {
     fn render(&self) -> Vec<N> {
-        render(&self.public, Some(self.player), &self.tiles)
+        render(&self.public, Some(self.player), &self.tiles, self.locale)
     }
 }
 
-static CORP_TABLE_HEADER: &'static [&'static str] =
-    &["Corporation", "Size", "Value", "Shares", "Minor", "Major"];
+static CORP_TABLE_HEADER: &'static [Msg] = &[
+    Msg::HeaderCorporation,
+    Msg::HeaderSize,
+    Msg::HeaderValue,
+    Msg::HeaderShares,
+    Msg::HeaderMinor,
+    Msg::HeaderMajor,
+    Msg::HeaderMajorHolder,
+    Msg::HeaderMinorHolder,
+];
 
-const ROW_PAD: &str = "   ";
+pub(crate) const ROW_PAD: &str = "   ";
 
 impl PubState {
-    fn corp_table(&self) -> N {
+    /// A share price, marked with which way it has moved since the end of the last round when
+    /// using the market variant.
+    fn render_price(&self, corp: &Corp, value: usize) -> Vec<N> {
+        let mut price = vec![N::text(format!("${}", value))];
+        let last = match self.market {
+            Some(ref market) if self.board.corp_size(corp) > 0 => market.last_price(corp),
+            _ => None,
+        };
+        match last {
+            Some(last) if last > 0 && value > last => {
+                price.push(N::Fg(PRICE_RISING_COLOR.into(), vec![N::text(" ▲")]))
+            }
+            Some(last) if last > 0 && value < last => {
+                price.push(N::Fg(PRICE_FALLING_COLOR.into(), vec![N::text(" ▼")]))
+            }
+            _ => {}
+        }
+        price
+    }
+
+    fn corp_table(&self, locale: Locale) -> N {
         let mut rows: Vec<Row> = vec![
             row_pad(
                 &CORP_TABLE_HEADER
                     .iter()
-                    .map(|h| (A::Left, vec![N::Bold(vec![N::text(*h)])]))
+                    .map(|h| (A::Left, vec![N::Bold(vec![N::text(locale.template(*h))])]))
                     .collect::<Row>(),
                 ROW_PAD,
             ),
         ];
+        let races = self.majority_races();
         rows.extend(
-            Corp::iter()
+            self.roster
+                .corps()
+                .iter()
                 .map(|c| {
                     let size = self.board.corp_size(c);
-                    let value = c.value(size);
+                    let value = self.share_price(c);
+                    let race = races.iter().find(|r| r.corp == *c);
                     row_pad(
                         &[
-                            (A::Left, vec![c.render()]),
+                            (A::Left, vec![self.roster.render(c)]),
                             (A::Left, vec![N::text(format!("{}", size))]),
-                            (A::Left, vec![N::text(format!("${}", value))]),
+                            (A::Left, self.render_price(c, value)),
                             (
                                 A::Left,
-                                vec![
-                                    N::text(format!(
-                                        "{} left",
+                                locale.fill(
+                                    Msg::SharesLeft,
+                                    vec![N::text(format!(
+                                        "{}",
                                         self.shares.get(c).expect("expected corp to have shares")
-                                    )),
-                                ],
+                                    ))],
+                                ),
+                            ),
+                            (
+                                A::Left,
+                                vec![N::text(format!("${}", self.prices.minor_bonus(c, size)))],
+                            ),
+                            (
+                                A::Left,
+                                vec![N::text(format!("${}", self.prices.major_bonus(c, size)))],
                             ),
-                            (A::Left, vec![N::text(format!("${}", value * MINOR_MULT))]),
-                            (A::Left, vec![N::text(format!("${}", value * MAJOR_MULT))]),
+                            (A::Left, render_holders(race, Position::Major, locale)),
+                            (A::Left, render_holders(race, Position::Minor, locale)),
                         ],
                         ROW_PAD,
                     )
@@ -117,60 +210,288 @@ impl PubState {
         N::Table(rows)
     }
 
-    fn render_remaining_tiles_text(&self) -> N {
+    fn render_remaining_tiles_text(&self, locale: Locale) -> N {
         N::Fg(
             GREY.into(),
-            vec![
-                N::text("Draw tiles remaining: "),
-                N::Bold(vec![N::text(format!("{}", self.remaining_tiles))]),
-            ],
+            locale.fill(
+                Msg::DrawTilesRemaining,
+                vec![N::Bold(vec![N::text(format!("{}", self.remaining_tiles))])],
+            ),
         )
     }
 
-    fn player_table(&self, player: Option<usize>) -> N {
-        let mut rows: Vec<Row> = vec![self.player_header()];
+    fn player_table(&self, player: Option<usize>, locale: Locale) -> N {
+        let mut rows: Vec<Row> = vec![self.player_header(locale)];
         let num_players = self.players.len();
         for p_offset in 0..num_players {
             let p = player
                 .map(|p| (p + p_offset) % num_players)
                 .unwrap_or(p_offset);
-            rows.push(self.player_row(p));
+            rows.push(self.player_row(p, locale));
         }
         N::Table(rows)
     }
 
-    fn player_header(&self) -> Row {
+    fn player_header(&self, locale: Locale) -> Row {
         let mut header_row: Row = vec![
-            (A::Left, vec![N::Bold(vec![N::text("Player")])]),
-            (A::Left, vec![N::Bold(vec![N::text("Cash")])]),
+            (
+                A::Left,
+                vec![N::Bold(vec![N::text(locale.template(Msg::HeaderPlayer))])],
+            ),
+            (
+                A::Left,
+                vec![N::Bold(vec![N::text(locale.template(Msg::HeaderCash))])],
+            ),
+            (
+                A::Left,
+                vec![N::Bold(vec![N::text(locale.template(Msg::HeaderWorth))])],
+            ),
         ];
-        for c in Corp::iter() {
-            header_row.push((A::Left, vec![c.render_abbrev()]));
+        if self.has_debt() {
+            header_row.push((
+                A::Left,
+                vec![N::Bold(vec![N::text(locale.template(Msg::HeaderDebt))])],
+            ));
+        }
+        for c in &self.roster.corps() {
+            header_row.push((A::Left, vec![self.roster.render_abbrev(c)]));
+        }
+        if self.open_tiles() {
+            header_row.push((
+                A::Left,
+                vec![N::Bold(vec![N::text(locale.template(Msg::HeaderTiles))])],
+            ));
         }
         row_pad(&header_row, ROW_PAD)
     }
 
-    fn player_row(&self, player: usize) -> Row {
+    fn player_row(&self, player: usize, locale: Locale) -> Row {
+        let mut player_cell: Vec<N> = vec![N::Player(player)];
+        let seat_msg = match self.players[player].seat {
+            Seat::Human => None,
+            Seat::Bot => Some(Msg::SeatBot),
+            Seat::Liquidated => Some(Msg::SeatResigned),
+        };
+        if let Some(msg) = seat_msg {
+            player_cell.push(N::Fg(GREY.into(), vec![N::text(locale.template(msg))]));
+        }
+        let pub_player = &self.players[player];
         let mut player_row: Row = vec![
-            (A::Left, vec![N::Player(player)]),
+            (A::Left, player_cell),
             (
                 A::Left,
-                vec![N::text(format!("${}", self.players[player].money))],
+                vec![match pub_player.money {
+                    Some(money) => N::text(format!("${}", money)),
+                    None => N::Fg(GREY.into(), vec![redacted()]),
+                }],
             ),
+            (A::Left, vec![self.render_worth(player)]),
         ];
-        for c in Corp::iter() {
+        if self.has_debt() {
+            player_row.push((A::Left, vec![N::text(format!("${}", pub_player.debt))]));
+        }
+        for c in &self.roster.corps() {
             player_row.push((
                 A::Left,
-                vec![
-                    N::text(format!(
-                        "{}",
-                        self.players[player].shares.get(c).cloned().unwrap_or(0)
-                    )),
-                ],
+                vec![match pub_player.shares {
+                    Some(ref shares) => {
+                        N::text(format!("{}", shares.get(c).cloned().unwrap_or(0)))
+                    }
+                    None => N::Fg(GREY.into(), vec![redacted()]),
+                }],
+            ));
+        }
+        if self.open_tiles() {
+            player_row.push((
+                A::Left,
+                vec![match pub_player.tiles {
+                    Some(ref tiles) => comma_list(tiles.iter().map(|t| t.render()).collect()),
+                    None => N::Fg(GREY.into(), vec![redacted()]),
+                }],
             ));
         }
         row_pad(&player_row, ROW_PAD)
     }
+
+    /// Net worth from public information. When cash is hidden it shows the value of shares less
+    /// debt as a lower bound.
+    fn render_worth(&self, player: usize) -> N {
+        match (self.net_worth(player), self.min_worth(player)) {
+            (Some(worth), _) => N::text(format!("${}", worth)),
+            (None, Some(value)) => N::Fg(GREY.into(), vec![N::text(format!("${}+", value))]),
+            (None, None) => N::Fg(GREY.into(), vec![redacted()]),
+        }
+    }
+
+    fn team_table(&self, locale: Locale) -> N {
+        let mut rows: Vec<Row> = vec![
+            row_pad(
+                &[Msg::HeaderTeam, Msg::HeaderPlayer, Msg::HeaderCash, Msg::HeaderWorth]
+                    .iter()
+                    .map(|h| (A::Left, vec![N::Bold(vec![N::text(locale.template(*h))])]))
+                    .collect::<Row>(),
+                ROW_PAD,
+            ),
+        ];
+        for (i, team) in self.teams.iter().enumerate() {
+            let money: Option<usize> = team.iter().map(|p| self.players[*p].money).sum();
+            let worth: Option<usize> = team.iter().map(|p| self.net_worth(*p)).sum();
+            rows.push(row_pad(
+                &[
+                    (
+                        A::Left,
+                        locale.fill(Msg::TeamName, vec![N::text(format!("{}", i + 1))]),
+                    ),
+                    (
+                        A::Left,
+                        vec![comma_list(team.iter().map(|p| N::Player(*p)).collect())],
+                    ),
+                    (A::Left, vec![render_total(money)]),
+                    (A::Left, vec![render_total(worth)]),
+                ],
+                ROW_PAD,
+            ));
+        }
+        N::Table(rows)
+    }
+
+    /// Whether other players' tiles are shown, as they are when playing with open tiles.
+    fn has_debt(&self) -> bool {
+        self.players.iter().any(|p| p.debt > 0)
+    }
+
+    fn open_tiles(&self) -> bool {
+        self.players.iter().filter(|p| p.tiles.is_some()).count() > 1
+    }
+}
+
+static REPORT_TABLE_HEADER: &'static [Msg] = &[
+    Msg::HeaderPlayer,
+    Msg::HeaderCashBefore,
+    Msg::HeaderCorporation,
+    Msg::HeaderMajor,
+    Msg::HeaderMinor,
+    Msg::HeaderSale,
+    Msg::HeaderLoans,
+    Msg::HeaderFinalCash,
+    Msg::HeaderDebt,
+    Msg::HeaderPlacing,
+    Msg::HeaderBehind,
+];
+
+impl EndGameReport {
+    /// A table of each player's liquidation, in placing order. Players are listed on their
+    /// first row with a row for each corporation they were paid by.
+    pub fn render(&self, locale: Locale, roster: &Roster) -> N {
+        let mut rows: Vec<Row> = vec![
+            row_pad(
+                &REPORT_TABLE_HEADER
+                    .iter()
+                    .map(|h| (A::Left, vec![N::Bold(vec![N::text(locale.template(*h))])]))
+                    .collect::<Row>(),
+                ROW_PAD,
+            ),
+        ];
+        let mut order: Vec<usize> = (0..self.players.len()).collect();
+        order.sort_by_key(|p| self.players[*p].placing);
+        let money = |amount: usize| vec![N::text(format!("${}", amount))];
+        for p in order {
+            let report = &self.players[p];
+            let num_rows = report.payouts.len().max(1);
+            for i in 0..num_rows {
+                let mut row: Row = if i == 0 {
+                    vec![
+                        (A::Left, vec![N::Player(p)]),
+                        (A::Left, money(report.cash_before)),
+                    ]
+                } else {
+                    vec![(A::Left, vec![]), (A::Left, vec![])]
+                };
+                match report.payouts.get(i) {
+                    Some(payout) => row.extend(vec![
+                        (A::Left, vec![roster.render(&payout.corp)]),
+                        (A::Left, money(payout.major_bonus)),
+                        (A::Left, money(payout.minor_bonus)),
+                        (A::Left, money(payout.sale)),
+                    ]),
+                    None => row.extend(vec![
+                        (A::Left, vec![]),
+                        (A::Left, vec![]),
+                        (A::Left, vec![]),
+                        (A::Left, vec![]),
+                    ]),
+                }
+                if i == 0 {
+                    row.extend(vec![
+                        (A::Left, money(report.loans)),
+                        (A::Left, vec![N::Bold(money(report.final_cash))]),
+                        (A::Left, money(report.debt)),
+                        (A::Left, vec![N::text(format!("{}", report.placing))]),
+                        (A::Left, money(report.gap)),
+                    ]);
+                }
+                rows.push(row_pad(&row, ROW_PAD));
+            }
+        }
+        N::Table(rows)
+    }
+}
+
+/// A team total, which is only shown if every member's value is visible.
+fn render_total(total: Option<usize>) -> N {
+    match total {
+        Some(total) => N::text(format!("${}", total)),
+        None => N::Fg(GREY.into(), vec![redacted()]),
+    }
+}
+
+pub fn comma_list(nodes: Vec<N>) -> N {
+    N::Group(
+        nodes
+            .into_iter()
+            .enumerate()
+            .flat_map(|(i, n)| {
+                let mut ns: Vec<N> = vec![];
+                if i > 0 {
+                    ns.push(N::text(", "));
+                }
+                ns.push(n);
+                ns
+            })
+            .collect(),
+    )
+}
+
+fn render_holders(race: Option<&MajorityRace>, position: Position, locale: Locale) -> Vec<N> {
+    let race = match race {
+        Some(r) => r,
+        None => return vec![],
+    };
+    let holders = match position {
+        Position::Major => &race.major,
+        Position::Minor => &race.minor,
+        Position::Outside => return vec![],
+    };
+    if holders.is_empty() {
+        return vec![N::Fg(GREY.into(), vec![N::text("-")])];
+    }
+    let mut content: Vec<N> = vec![];
+    for (i, p) in holders.iter().enumerate() {
+        if i > 0 {
+            content.push(N::text(", "));
+        }
+        content.push(N::Player(*p));
+    }
+    content.push(N::text(format!(" {}", race.position_shares(position))));
+    content.push(N::Fg(
+        GREY.into(),
+        vec![N::text(if holders.len() > 1 {
+            locale.template(Msg::Tied).to_string()
+        } else {
+            format!(" (+{})", race.lead(position))
+        })],
+    ));
+    content
 }
 
 fn tile_background(c: Color) -> N {
@@ -195,32 +516,31 @@ fn empty_color(l: Loc) -> Color {
     }
 }
 
-fn corp_main_text_thin(c: &Corp, size: usize) -> Vec<N> {
+fn corp_main_text_thin(roster: &Roster, c: &Corp, value: usize) -> Vec<N> {
     vec![
         N::Fg(
-            c.color().inv().mono().into(),
+            roster.color(c).inv().mono().into(),
             vec![
                 N::Align(
                     A::Center,
                     TILE_WIDTH,
-                    vec![N::text(format!("{}\n${}", c.abbrev(), c.value(size)))],
+                    vec![N::text(format!("{}\n${}", roster.abbrev(c), value))],
                 ),
             ],
         ),
     ]
 }
 
-fn corp_main_text_wide(c: &Corp, size: usize) -> Vec<N> {
-    let mut c_name = c.name();
-    c_name.truncate(TILE_WIDTH * 2 - 2);
+fn corp_main_text_wide(roster: &Roster, c: &Corp, value: usize) -> Vec<N> {
+    let c_name: String = roster.name(c).chars().take(TILE_WIDTH * 2 - 2).collect();
     vec![
         N::Fg(
-            c.color().inv().mono().into(),
+            roster.color(c).inv().mono().into(),
             vec![
                 N::Align(
                     A::Center,
                     TILE_WIDTH * 2,
-                    vec![N::text(format!("{}\n${}", c_name, c.value(size)))],
+                    vec![N::text(format!("{}\n${}", c_name, value))],
                 ),
             ],
         ),
@@ -228,12 +548,24 @@ fn corp_main_text_wide(c: &Corp, size: usize) -> Vec<N> {
 }
 
 impl Board {
-    pub fn render(&self, player_tiles: &[Loc]) -> N {
+    /// Where a cell is drawn on the canvas, with odd rows shifted half a tile on hex boards.
+    fn render_pos(&self, l: &Loc) -> (usize, usize) {
+        let offset = if self.geometry.hex && l.row % 2 == 1 {
+            TILE_WIDTH / 2
+        } else {
+            0
+        };
+        (l.col * TILE_WIDTH + offset, l.row * TILE_HEIGHT)
+    }
+
+    pub fn render<F>(&self, player_tiles: &[Loc], price: F, roster: &Roster) -> N
+    where
+        F: Fn(&Corp) -> usize,
+    {
         let mut layers = vec![];
         // Tile backgrounds and location text.
-        for l in Loc::all() {
-            let render_x = l.col * TILE_WIDTH;
-            let render_y = l.row * TILE_HEIGHT;
+        for l in self.geometry.locs() {
+            let (render_x, render_y) = self.render_pos(&l);
             match self.get_tile(&l) {
                 Tile::Empty => {
                     layers.push((render_x, render_y, vec![tile_background(empty_color(l))]));
@@ -262,7 +594,7 @@ impl Board {
                     ));
                 }
                 Tile::Corp(ref c) => {
-                    layers.push((render_x, render_y, vec![tile_background(c.color())]));
+                    layers.push((render_x, render_y, vec![tile_background(roster.color(c))]));
                 }
                 Tile::Discarded => {}
             }
@@ -270,8 +602,7 @@ impl Board {
         // Player tiles.
         for t in player_tiles {
             let l = *t;
-            let render_x = l.col * TILE_WIDTH;
-            let render_y = l.row * TILE_HEIGHT;
+            let (render_x, render_y) = self.render_pos(&l);
             layers.push((
                 render_x,
                 render_y,
@@ -298,15 +629,17 @@ impl Board {
         }
         // Corp text.
         layers.extend(
-            Corp::iter()
+            self.corps
+                .iter()
                 .flat_map(|c| {
                     let mut c_text = vec![];
                     // Find the widest lines.
                     // `widths` is a tuple of x, y, width.
-                    let widths: Vec<(usize, usize, usize)> = board::rows()
+                    let width = self.geometry.width;
+                    let widths: Vec<(usize, usize, usize)> = (0..self.geometry.height)
                         .flat_map(|row| {
                             let mut start: Option<usize> = None;
-                            board::cols()
+                            (0..width)
                                 .filter_map(|col| {
                                     let l = Loc { row: row, col: col };
                                     match self.get_tile(&l) {
@@ -314,7 +647,7 @@ impl Board {
                                             if start.is_none() {
                                                 start = Some(col);
                                             }
-                                            if col == board::WIDTH - 1 {
+                                            if col == width - 1 {
                                                 Some(
                                                     (start.unwrap(), row, col - start.unwrap() + 1),
                                                 )
@@ -335,13 +668,17 @@ impl Board {
                         .collect();
                     if !widths.is_empty() {
                         let (x, y, w) = widths[(widths.len() - 1) / 2];
+                        let (render_x, render_y) = self.render_pos(&Loc {
+                            row: y,
+                            col: x + (w - 1) / 2,
+                        });
                         c_text.push((
-                            (x + (w - 1) / 2) * TILE_WIDTH,
-                            y * TILE_HEIGHT,
+                            render_x,
+                            render_y,
                             if w > 1 {
-                                corp_main_text_wide(c, self.corp_size(c))
+                                corp_main_text_wide(roster, c, price(c))
                             } else {
-                                corp_main_text_thin(c, self.corp_size(c))
+                                corp_main_text_thin(roster, c, price(c))
                             },
                         ));
                     }
@@ -368,17 +705,17 @@ impl Corp {
 }
 
 impl CanEnd {
-    fn render_end_text(&self) -> N {
+    fn render_end_text(&self, locale: Locale) -> N {
         N::Fg(
             GREY.into(),
             vec![
                 match *self {
                     CanEnd::Triggered => {
-                        N::Bold(vec![N::text("The game will end at the end of this turn")])
+                        N::Bold(vec![N::text(locale.template(Msg::EndTriggered))])
                     }
-                    CanEnd::Finished => N::Bold(vec![N::text("The game has ended")]),
-                    CanEnd::True => N::Bold(vec![N::text("The end of the game can be triggered")]),
-                    CanEnd::False(ref caf) => caf.render_end_text(),
+                    CanEnd::Finished => N::Bold(vec![N::text(locale.template(Msg::EndFinished))]),
+                    CanEnd::True => N::Bold(vec![N::text(locale.template(Msg::EndCanTrigger))]),
+                    CanEnd::False(ref caf) => caf.render_end_text(locale),
                 },
             ],
         )
@@ -386,18 +723,17 @@ impl CanEnd {
 }
 
 impl CanEndFalse {
-    fn render_end_text(&self) -> N {
+    fn render_end_text(&self, locale: Locale) -> N {
         if self.largest == 0 {
-            return N::text("No corporations have been founded yet");
+            return N::text(locale.template(Msg::NoCorpsFounded));
         }
-        N::Group(vec![
-            N::text("Largest corporation is "),
-            N::Bold(vec![N::text(format!("{}", self.largest))]),
-            N::text(" of "),
-            N::Bold(vec![N::text(format!("{}", GAME_END_SIZE))]),
-            N::text(", "),
-            N::Bold(vec![N::text(format!("{}", self.unsafe_count))]),
-            N::text(" unsafe remaining"),
-        ])
+        N::Group(locale.fill(
+            Msg::LargestCorp,
+            vec![
+                N::Bold(vec![N::text(format!("{}", self.largest))]),
+                N::Bold(vec![N::text(format!("{}", GAME_END_SIZE))]),
+                N::Bold(vec![N::text(format!("{}", self.unsafe_count))]),
+            ],
+        ))
     }
 }
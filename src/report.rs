@@ -0,0 +1,205 @@
+use serde_derive::{Serialize, Deserialize};
+
+use brdgme_game::{Gamer, Stat};
+
+use std::collections::HashMap;
+
+use crate::corp::Corp;
+use crate::stats::Stats;
+use crate::{Game, Player};
+
+/// What a player received from a corporation when it was liquidated at the end of the game.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct CorpPayout {
+    pub corp: Corp,
+    pub major_bonus: usize,
+    pub minor_bonus: usize,
+    pub sale: usize,
+}
+
+#[derive(Default, PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerReport {
+    pub cash_before: usize,
+    pub payouts: Vec<CorpPayout>,
+    /// Cash used to repay loans once everything was liquidated.
+    #[serde(default)]
+    pub loans: usize,
+    pub final_cash: usize,
+    /// Debt which couldn't be repaid, which is subtracted from the player's cash for placings.
+    #[serde(default)]
+    pub debt: usize,
+    pub placing: usize,
+    /// How far the player's cash less debt finished behind the winner's.
+    pub gap: usize,
+}
+
+/// A breakdown of how the game was settled, recorded when it ends.
+#[derive(Default, PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct EndGameReport {
+    pub players: Vec<PlayerReport>,
+}
+
+impl EndGameReport {
+    /// Start a report from players' cash before anything is liquidated.
+    pub fn new(players: &[Player]) -> Self {
+        EndGameReport {
+            players: players
+                .iter()
+                .map(|p| PlayerReport {
+                    cash_before: p.money,
+                    ..PlayerReport::default()
+                })
+                .collect(),
+        }
+    }
+
+    /// Record what each player received from a corporation using their stats from before it
+    /// was liquidated.
+    pub fn add_payouts(&mut self, corp: Corp, before: &[Stats], players: &[Player]) {
+        for (p, report) in self.players.iter_mut().enumerate() {
+            let after = &players[p].stats;
+            let payout = CorpPayout {
+                corp,
+                major_bonus: after.major_bonus_sum - before[p].major_bonus_sum,
+                minor_bonus: after.minor_bonus_sum - before[p].minor_bonus_sum,
+                sale: after.sell_sum - before[p].sell_sum,
+            };
+            if payout.major_bonus + payout.minor_bonus + payout.sale > 0 {
+                report.payouts.push(payout);
+            }
+        }
+    }
+
+    /// Record what each player repaid on their loans and what they still owe, using their cash
+    /// from before loans were settled.
+    pub fn add_loans(&mut self, cash: &[usize], players: &[Player]) {
+        for (p, report) in self.players.iter_mut().enumerate() {
+            report.loans = cash[p] - players[p].money;
+            report.debt = players[p].debt();
+        }
+    }
+
+    /// Fill in final cash and placings once the game is finished.
+    pub fn finish(&mut self, game: &Game) {
+        let placings = game.placings();
+        // Resigned players can finish with more than the winner, who they are placed below.
+        let winner = placings
+            .iter()
+            .position(|placing| *placing == 1)
+            .map(|p| game.team_score(p))
+            .unwrap_or(0);
+        for (p, report) in self.players.iter_mut().enumerate() {
+            report.final_cash = game.players[p].money;
+            report.placing = placings[p];
+            report.gap = (winner - game.team_score(p)).max(0) as usize;
+        }
+    }
+}
+
+impl PlayerReport {
+    pub fn bonuses(&self) -> usize {
+        self.payouts
+            .iter()
+            .map(|p| p.major_bonus + p.minor_bonus)
+            .sum()
+    }
+
+    pub fn sales(&self) -> usize {
+        self.payouts.iter().map(|p| p.sale).sum()
+    }
+
+    pub fn to_brdgme_stats(&self) -> HashMap<String, Stat> {
+        let mut s: HashMap<String, Stat> = HashMap::new();
+        s.insert(
+            "Cash before liquidation".to_string(),
+            Stat::Int(self.cash_before as i32),
+        );
+        s.insert(
+            "Liquidation bonuses".to_string(),
+            Stat::Int(self.bonuses() as i32),
+        );
+        s.insert(
+            "Liquidation sales".to_string(),
+            Stat::Int(self.sales() as i32),
+        );
+        s.insert("Loans repaid".to_string(), Stat::Int(self.loans as i32));
+        s.insert("Final cash".to_string(), Stat::Int(self.final_cash as i32));
+        s.insert("Unpaid debt".to_string(), Stat::Int(self.debt as i32));
+        s.insert("Behind winner".to_string(), Stat::Int(self.gap as i32));
+        s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use brdgme_game::Gamer;
+
+    use crate::corp::Corp;
+    use crate::loans::Loan;
+    use crate::Game;
+
+    #[test]
+    fn report_breaks_down_liquidation() {
+        let mut g: Game = "AAAAAAAAAAA0
+                           ..........1."
+            .into();
+        g.players[0].shares.insert(Corp::American, 3);
+        let money = g.players[0].money;
+        g.end().expect("expected ending the game to work");
+        let report = g.end_report.clone().expect("expected an end game report");
+        let p0 = &report.players[0];
+        assert_eq!(money, p0.cash_before);
+        assert_eq!(1, p0.payouts.len());
+        assert_eq!(3 * g.options.prices.value(&Corp::American, 11), p0.sales());
+        assert_eq!(g.players[0].money, p0.final_cash);
+        assert_eq!(p0.cash_before + p0.bonuses() + p0.sales(), p0.final_cash);
+        assert_eq!(1, p0.placing);
+        assert_eq!(p0.final_cash - report.players[1].final_cash, report.players[1].gap);
+        assert_eq!(0, p0.gap);
+    }
+
+    #[test]
+    fn unpaid_debt_counts_against_placing() {
+        let mut g: Game = "AAAAAAAAAAA0
+                           ..........1."
+            .into();
+        g.players[0].shares.insert(Corp::American, 3);
+        g.players[0].money = 1000;
+        g.players[0].loans.push(Loan {
+            owed: 50000,
+            turns_left: None,
+        });
+        g.players[1].money = 2000;
+        g.end().expect("expected ending the game to work");
+        let report = g.end_report.clone().expect("expected an end game report");
+        let p0 = &report.players[0];
+        assert_eq!(0, p0.final_cash);
+        assert!(p0.loans > 0);
+        assert_eq!(
+            p0.cash_before + p0.bonuses() + p0.sales() - p0.loans,
+            p0.final_cash
+        );
+        assert_eq!(50000 - p0.loans, p0.debt);
+        assert_eq!(p0.debt, g.players[0].debt());
+        assert_eq!(2, p0.placing);
+        assert_eq!(1, report.players[1].placing);
+        assert_eq!(2000 + p0.debt, p0.gap);
+        assert_eq!(vec![-(p0.debt as f32), 2000.0], g.points());
+    }
+
+    #[test]
+    fn gap_is_measured_from_the_winner() {
+        let mut g: Game = "012".into();
+        g.players[0].money = 6000;
+        g.players[1].money = 9000;
+        g.players[2].money = 4000;
+        g.resigned = vec![1];
+        g.end().expect("expected ending the game to work");
+        let report = g.end_report.clone().expect("expected an end game report");
+        assert_eq!(1, report.players[0].placing);
+        assert_eq!(3, report.players[1].placing);
+        assert_eq!(0, report.players[0].gap);
+        assert_eq!(0, report.players[1].gap);
+        assert_eq!(2000, report.players[2].gap);
+    }
+}
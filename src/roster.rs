@@ -0,0 +1,208 @@
+use serde_derive::{Serialize, Deserialize};
+
+use brdgme_color::Color;
+use brdgme_markup::Node as N;
+
+use std::fmt;
+
+use crate::corp::{Corp, CORPS};
+use crate::errors::AcquireError;
+use crate::Game;
+
+/// How a corporation is presented in a game.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CorpDef {
+    /// The slot the corporation occupies. There is no separate tier, the price table's base
+    /// prices are keyed by slot, so choosing the slot chooses the tier.
+    pub corp: Corp,
+    pub name: String,
+    pub abbrev: String,
+    /// Red, green and blue components.
+    pub color: (u8, u8, u8),
+}
+
+impl CorpDef {
+    fn new(corp: Corp, name: &str) -> Self {
+        let color = corp.color();
+        CorpDef {
+            corp,
+            name: name.to_string(),
+            abbrev: name.chars().take(2).collect::<String>().to_uppercase(),
+            color: (color.r, color.g, color.b),
+        }
+    }
+}
+
+/// The corporations used in a game.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Roster {
+    pub corps: Vec<CorpDef>,
+}
+
+impl Default for Roster {
+    fn default() -> Self {
+        Roster::classic()
+    }
+}
+
+/// A corporation as it is named in a game, for parsing commands.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CorpName {
+    pub corp: Corp,
+    pub name: String,
+}
+
+impl fmt::Display for CorpName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl Roster {
+    /// All seven corporations as this game has always named them.
+    pub fn classic() -> Self {
+        Roster {
+            corps: CORPS.iter().map(|c| CorpDef::new(*c, &c.name())).collect(),
+        }
+    }
+
+    /// The names from the modern editions, with Luxor taking Sackson's place.
+    pub fn modern() -> Self {
+        Roster {
+            corps: CORPS
+                .iter()
+                .map(|c| match *c {
+                    Corp::Sackson => CorpDef::new(*c, "Luxor"),
+                    _ => CorpDef::new(*c, &c.name()),
+                })
+                .collect(),
+        }
+    }
+
+    /// A quicker game with five corporations, dropping one from each of the outer tiers.
+    pub fn quick() -> Self {
+        Roster {
+            corps: Roster::classic()
+                .corps
+                .into_iter()
+                .filter(|d| d.corp != Corp::Sackson && d.corp != Corp::Tower)
+                .collect(),
+        }
+    }
+
+    /// Check there are at least two corporations and that slots and abbreviations are unique.
+    pub fn validate(&self) -> Result<(), AcquireError> {
+        let invalid = self.corps.len() < 2 || self.corps.iter().enumerate().any(|(i, d)| {
+            d.name.is_empty() || d.abbrev.is_empty()
+                || self.corps[..i]
+                    .iter()
+                    .any(|o| o.corp == d.corp || o.abbrev == d.abbrev || o.name == d.name)
+        });
+        if invalid {
+            return Err(AcquireError::InvalidRoster);
+        }
+        Ok(())
+    }
+
+    /// The corporations in play, in roster order.
+    pub fn corps(&self) -> Vec<Corp> {
+        self.corps.iter().map(|d| d.corp).collect()
+    }
+
+    pub fn contains(&self, corp: &Corp) -> bool {
+        self.corps.iter().any(|d| d.corp == *corp)
+    }
+
+    /// Names of the given corporations for use in command parsers.
+    pub fn names(&self, corps: &[Corp]) -> Vec<CorpName> {
+        corps
+            .iter()
+            .map(|c| CorpName {
+                corp: *c,
+                name: self.name(c),
+            })
+            .collect()
+    }
+
+    fn def(&self, corp: &Corp) -> Option<&CorpDef> {
+        self.corps.iter().find(|d| d.corp == *corp)
+    }
+
+    pub fn name(&self, corp: &Corp) -> String {
+        self.def(corp)
+            .map(|d| d.name.clone())
+            .unwrap_or_else(|| corp.name())
+    }
+
+    pub fn abbrev(&self, corp: &Corp) -> String {
+        self.def(corp)
+            .map(|d| d.abbrev.clone())
+            .unwrap_or_else(|| corp.abbrev())
+    }
+
+    pub fn color(&self, corp: &Corp) -> Color {
+        self.def(corp)
+            .map(|d| Color {
+                r: d.color.0,
+                g: d.color.1,
+                b: d.color.2,
+            })
+            .unwrap_or_else(|| corp.color())
+    }
+
+    pub fn render(&self, corp: &Corp) -> N {
+        N::Bold(vec![
+            N::Fg(self.color(corp).into(), vec![N::text(self.name(corp))]),
+        ])
+    }
+
+    pub fn render_abbrev(&self, corp: &Corp) -> N {
+        N::Fg(
+            self.color(corp).into(),
+            vec![N::Bold(vec![N::text(self.abbrev(corp))])],
+        )
+    }
+}
+
+impl Game {
+    /// A corporation as it is named in this game.
+    pub fn render_corp(&self, corp: &Corp) -> N {
+        self.options.roster.render(corp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CorpDef, Roster};
+    use crate::corp::Corp;
+    use crate::errors::AcquireError;
+    use crate::options::Options;
+    use crate::Game;
+
+    #[test]
+    fn rosters_are_valid() {
+        assert!(Roster::classic().validate().is_ok());
+        assert!(Roster::modern().validate().is_ok());
+        assert_eq!(5, Roster::quick().corps().len());
+        assert_eq!("Luxor", Roster::modern().name(&Corp::Sackson));
+        assert_eq!("LU", Roster::modern().abbrev(&Corp::Sackson));
+        let mut roster = Roster::quick();
+        roster.corps[1].abbrev = roster.corps[0].abbrev.clone();
+        assert_eq!(Err(AcquireError::InvalidRoster), roster.validate());
+        assert_eq!("ÖL", CorpDef::new(Corp::Sackson, "Ölkonzern").abbrev);
+    }
+
+    #[test]
+    fn game_follows_roster() {
+        let options = Options {
+            roster: Roster::quick(),
+            ..Options::default()
+        };
+        let g = Game::new_with_options(3, options)
+            .expect("expected new game")
+            .0;
+        assert_eq!(5, g.shares.len());
+        assert_eq!(5, g.board.available_corps().len());
+        assert!(!g.board.available_corps().contains(&Corp::Tower));
+    }
+}
@@ -0,0 +1,197 @@
+use serde_derive::Serialize;
+use serde_json::{Map, Value};
+
+use brdgme_game::errors::GameError;
+
+use std::error::Error;
+use std::fmt;
+
+use crate::Game;
+
+/// The layout of games written by `Game::to_save`.
+///
+/// 1. The original layout, with the board stored as a list of tiles. Saves from before the
+///    format was versioned are the game on its own rather than wrapped with a version.
+/// 2. The board holds its corporations and geometry alongside its tiles.
+pub const SAVE_VERSION: u64 = 2;
+
+/// Upgrades from each version to the next, the first upgrading version 1 saves.
+static MIGRATIONS: &'static [fn(Value) -> Result<Value, SaveError>] = &[migrate_board];
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum SaveError {
+    /// The save was written by a newer engine than this one.
+    TooNew { version: u64, supported: u64 },
+    /// The save doesn't match the layout for its version.
+    Invalid(String),
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SaveError::TooNew { version, supported } => write!(
+                f,
+                "save version {} is newer than version {}, the newest this engine can load",
+                version, supported
+            ),
+            SaveError::Invalid(ref message) => write!(f, "invalid save: {}", message),
+        }
+    }
+}
+
+impl Error for SaveError {}
+
+impl From<serde_json::Error> for SaveError {
+    fn from(err: serde_json::Error) -> Self {
+        SaveError::Invalid(err.to_string())
+    }
+}
+
+impl From<SaveError> for GameError {
+    fn from(err: SaveError) -> Self {
+        GameError::Internal {
+            message: err.to_string(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Save<'a> {
+    version: u64,
+    game: &'a Game,
+}
+
+fn invalid(message: &str) -> SaveError {
+    SaveError::Invalid(message.to_string())
+}
+
+/// Version 2 moved the list of tiles into a board object. The board's corporations and
+/// geometry default to the classic ones, which were all there was in version 1. `Board` also
+/// loads the old layout itself, for games deserialized directly rather than through
+/// `from_save`.
+fn migrate_board(mut game: Value) -> Result<Value, SaveError> {
+    {
+        let fields = game
+            .as_object_mut()
+            .ok_or_else(|| invalid("game must be an object"))?;
+        let tiles = fields
+            .remove("board")
+            .ok_or_else(|| invalid("game has no board"))?;
+        let mut board = Map::new();
+        board.insert("tiles".to_string(), tiles);
+        fields.insert("board".to_string(), Value::Object(board));
+    }
+    Ok(game)
+}
+
+/// Saves from before the format was versioned can be told apart by the shape of the board.
+fn unversioned(game: &Value) -> u64 {
+    match game.get("board") {
+        Some(&Value::Array(_)) => 1,
+        _ => 2,
+    }
+}
+
+impl Game {
+    /// Serialise the game along with the version of its layout.
+    pub fn to_save(&self) -> Result<String, SaveError> {
+        Ok(serde_json::to_string(&Save {
+            version: SAVE_VERSION,
+            game: self,
+        })?)
+    }
+
+    /// Load a game saved by this or any earlier version of the engine, upgrading it to the
+    /// current layout.
+    pub fn from_save(data: &str) -> Result<Game, SaveError> {
+        let (version, mut game) = match serde_json::from_str(data)? {
+            Value::Object(mut fields) => {
+                if fields.contains_key("version") {
+                    let version = fields
+                        .get("version")
+                        .and_then(Value::as_u64)
+                        .ok_or_else(|| invalid("version must be a positive number"))?;
+                    let game = fields
+                        .remove("game")
+                        .ok_or_else(|| invalid("save has no game"))?;
+                    (version, game)
+                } else {
+                    let game = Value::Object(fields);
+                    (unversioned(&game), game)
+                }
+            }
+            _ => return Err(invalid("save must be an object")),
+        };
+        if version > SAVE_VERSION {
+            return Err(SaveError::TooNew {
+                version,
+                supported: SAVE_VERSION,
+            });
+        }
+        if version == 0 {
+            return Err(invalid("version must be a positive number"));
+        }
+        for migrate in &MIGRATIONS[version as usize - 1..] {
+            game = migrate(game)?;
+        }
+        Ok(serde_json::from_value(game)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use brdgme_game::Gamer;
+
+    use super::{SaveError, MIGRATIONS, SAVE_VERSION};
+    use crate::board::{Loc, Tile};
+    use crate::corp::{Corp, CORPS};
+    use crate::{Game, Phase};
+
+    static FIXTURES: &'static [&'static str] = &[
+        include_str!("../tests/fixtures/save_v1.json"),
+        include_str!("../tests/fixtures/save_v2.json"),
+    ];
+
+    #[test]
+    fn every_version_loads() {
+        assert_eq!(SAVE_VERSION as usize - 1, MIGRATIONS.len());
+        assert_eq!(SAVE_VERSION as usize, FIXTURES.len());
+        for fixture in FIXTURES {
+            let g = Game::from_save(fixture).expect("expected save to load");
+            assert_eq!(
+                Phase::Buy {
+                    player: 0,
+                    remaining: 1,
+                },
+                g.phase
+            );
+            assert_eq!(Tile::Corp(Corp::American), g.board.get_tile(Loc { row: 0, col: 2 }));
+            assert_eq!(Tile::Unincorporated, g.board.get_tile(Loc { row: 4, col: 6 }));
+            assert_eq!(CORPS.to_vec(), g.board.corps);
+            assert_eq!(Some(&3), g.players[0].shares.get(&Corp::American));
+            assert_eq!(5200, g.players[0].money);
+        }
+    }
+
+    #[test]
+    fn unversioned_saves_deserialize_directly() {
+        let g: Game = serde_json::from_str(FIXTURES[0]).expect("expected game to deserialize");
+        assert_eq!(Tile::Corp(Corp::American), g.board.get_tile(Loc { row: 0, col: 2 }));
+        assert_eq!(CORPS.to_vec(), g.board.corps);
+        assert_eq!(Ok(g), Game::from_save(FIXTURES[0]));
+    }
+
+    #[test]
+    fn saves_round_trip_and_newer_saves_are_rejected() {
+        let g = Game::new(3).expect("expected new game").0;
+        let data = g.to_save().expect("expected save to work");
+        assert_eq!(Ok(g), Game::from_save(&data));
+        assert_eq!(
+            Err(SaveError::TooNew {
+                version: SAVE_VERSION + 1,
+                supported: SAVE_VERSION,
+            }),
+            Game::from_save(&format!("{{\"version\":{},\"game\":{{}}}}", SAVE_VERSION + 1))
+        );
+    }
+}
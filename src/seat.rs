@@ -0,0 +1,228 @@
+use serde_derive::{Serialize, Deserialize};
+
+use brdgme_game::Log;
+use brdgme_markup::Node as N;
+
+use crate::corp::Corp;
+use crate::errors::AcquireError;
+use crate::i18n::Msg;
+use crate::options::ResignPolicy;
+use crate::render::comma_list;
+use crate::undo::UndoBarrier;
+use crate::visibility::redacted;
+use crate::{Game, Phase};
+
+/// Who is in control of a player's seat.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Seat {
+    Human,
+    /// The player resigned and a bot plays out their turns using the default actions.
+    Bot,
+    /// The player resigned and their holdings were returned to the bank, the seat is skipped.
+    Liquidated,
+}
+
+impl Default for Seat {
+    fn default() -> Self {
+        Seat::Human
+    }
+}
+
+impl Game {
+    pub fn handle_resign_command(&mut self, player: usize) -> Result<Vec<Log>, AcquireError> {
+        self.ensure_not_finished()?;
+        let seat = match self.options.resign_policy {
+            ResignPolicy::Bot => Seat::Bot,
+            ResignPolicy::Liquidate => Seat::Liquidated,
+        };
+        self.resign(player, seat)
+    }
+
+    /// Hand a player's seat over to a bot, for example when a player has abandoned the game.
+    pub fn replace_with_bot(&mut self, player: usize) -> Result<Vec<Log>, AcquireError> {
+        self.ensure_not_finished()?;
+        let whose_turn = self.phase.whose_turn();
+        let mut logs = self.resign(player, Seat::Bot)?;
+        // This happens outside of commands, so undoing mustn't restore the seat.
+        self.clear_undo(UndoBarrier::Irreversible);
+        logs.extend(self.run_bots()?);
+        if self.phase.whose_turn() != whose_turn {
+            self.turn_started_at = None;
+        }
+        Ok(logs)
+    }
+
+    fn resign(&mut self, player: usize, seat: Seat) -> Result<Vec<Log>, AcquireError> {
+        match self.players.get(player).map(|p| p.seat) {
+            Some(Seat::Human) => {}
+            Some(_) => return Err(AcquireError::AlreadyResigned),
+            None => return Err(AcquireError::NoSuchPlayer(player)),
+        }
+        self.players[player].seat = seat;
+        self.resigned.push(player);
+        let msg = match seat {
+            Seat::Bot => Msg::ResignedBot,
+            _ => Msg::ResignedLiquidate,
+        };
+        Ok(self.public_logs(|l| l.fill(msg, vec![N::Player(player)])))
+    }
+
+    /// Play out any decisions for bot seats and skip liquidated seats, until a human needs to
+    /// act.
+    pub(crate) fn run_bots(&mut self) -> Result<Vec<Log>, AcquireError> {
+        let mut logs: Vec<Log> = vec![];
+        if self.finished {
+            return Ok(logs);
+        }
+        if self.players.iter().all(|p| p.seat != Seat::Human) {
+            logs.extend(self.public_logs(|l| {
+                vec![N::Bold(vec![N::text(l.template(Msg::AllResigned))])]
+            }));
+            logs.extend(self.end()?);
+            return Ok(logs);
+        }
+        while !self.finished {
+            let player = self.phase.whose_turn();
+            match self.players[player].seat {
+                Seat::Human => break,
+                Seat::Liquidated => {
+                    if let Phase::Play(_) = self.phase {
+                        logs.extend(self.end_turn()?);
+                        continue;
+                    }
+                }
+                Seat::Bot => {}
+            }
+            let command = self.default_command(player).ok_or_else(|| {
+                AcquireError::Internal("no default action is available".to_string())
+            })?;
+            logs.extend(self.run_command(player, command)?.0);
+            self.clear_undo(UndoBarrier::OtherPlayer(player));
+        }
+        if !self.finished {
+            // Liquidate seats once they're no longer involved in the current turn.
+            for player in 0..self.players.len() {
+                if self.players[player].seat == Seat::Liquidated
+                    && self.phase.main_turn_player() != player
+                    && self.phase.whose_turn() != player
+                {
+                    logs.extend(self.liquidate(player)?);
+                }
+            }
+        }
+        Ok(logs)
+    }
+
+    /// Sell a player's shares back to the bank at current value and discard their tiles.
+    fn liquidate(&mut self, player: usize) -> Result<Vec<Log>, AcquireError> {
+        let mut logs: Vec<Log> = vec![];
+        for corp in Corp::iter() {
+            let n = self.players[player].shares.get(corp).cloned().unwrap_or(0);
+            if n == 0 {
+                continue;
+            }
+            if self.board.corp_size(corp) > 0 {
+                logs.extend(self.sell(player, n, corp)?);
+            } else {
+                // Shares in corporations which have been merged away are worthless.
+                self.return_shares(player, n, corp)?;
+                logs.extend(self.redacted_logs(&[player], !self.shares_visible(), |l, shown| {
+                    l.fill(
+                        Msg::ReturnedToBank,
+                        vec![
+                            N::Player(player),
+                            if shown {
+                                N::Bold(vec![N::text(format!("{}", n))])
+                            } else {
+                                redacted()
+                            },
+                            self.render_corp(corp),
+                        ],
+                    )
+                }));
+            }
+        }
+        if !self.players[player].tiles.is_empty() {
+            let tiles: Vec<_> = self.players[player].tiles.drain(..).collect();
+            self.board.set_discarded(&tiles);
+            logs.extend(self.public_logs(|l| {
+                l.fill(
+                    Msg::Discarded,
+                    vec![
+                        N::Player(player),
+                        comma_list(tiles.iter().map(|t| t.render()).collect()),
+                    ],
+                )
+            }));
+        }
+        Ok(logs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use brdgme_game::{Gamer, Status};
+
+    use super::Seat;
+    use crate::board::{Loc, Tile};
+    use crate::corp::Corp;
+    use crate::errors::AcquireError;
+    use crate::undo::UndoBarrier;
+    use crate::{Game, Phase};
+
+    #[test]
+    fn resign_liquidates_and_skips_seat() {
+        let players = vec!["mick".to_string(), "steve".to_string(), "bob".to_string()];
+        let mut g: Game = "0.1
+                           ...
+                           ..2"
+            .into();
+        g.draw_tiles = (0..6).map(|col| Loc { row: 5, col }).collect();
+        g.command(1, "resign", &players)
+            .expect("expected resigning to work");
+        assert_eq!(Tile::Discarded, g.board.get_tile(Loc { row: 0, col: 2 }));
+        assert!(g.players[1].tiles.is_empty());
+        match g.status() {
+            Status::Active { eliminated, .. } => assert_eq!(vec![1], eliminated),
+            _ => panic!("expected game to be active"),
+        }
+        g.command(0, "play a1", &players)
+            .expect("expected 'play a1' to work");
+        g.command(0, "done", &players)
+            .expect("expected 'done' to work");
+        assert_eq!(Phase::Play(2), g.phase);
+    }
+
+    #[test]
+    fn replacing_with_bot_blocks_undo() {
+        let players = vec!["mick".to_string(), "steve".to_string(), "bob".to_string()];
+        let mut g: Game = "0.1
+                           ...
+                           ..2"
+            .into();
+        g.command(0, "play a1", &players)
+            .expect("expected 'play a1' to work");
+        g.replace_with_bot(2)
+            .expect("expected replacing with a bot to work");
+        assert_eq!(
+            Err(AcquireError::UndoBlocked(UndoBarrier::Irreversible)),
+            g.undo(0).map(|_| ())
+        );
+        assert_eq!(Seat::Bot, g.players[2].seat);
+    }
+
+    #[test]
+    fn liquidating_the_only_holder_leaves_no_bonuses() {
+        let players = vec!["mick".to_string(), "steve".to_string(), "bob".to_string()];
+        let mut g: Game = "AA0
+                           ..1
+                           ..2"
+            .into();
+        g.players[1].shares.insert(Corp::American, 1);
+        g.command(1, "resign", &players)
+            .expect("expected resigning to work");
+        assert!(g.players.iter().all(|p| p.shares.get(&Corp::American) != Some(&1)));
+        g.end().expect("expected ending the game to work");
+        assert!(g.players.iter().all(|p| p.stats.major_bonuses == 0));
+    }
+}
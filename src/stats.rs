@@ -21,6 +21,14 @@ pub struct Stats {
     pub major_bonuses: usize,
     pub minor_bonus_sum: usize,
     pub minor_bonuses: usize,
+    /// Shares held when the game ended, before they were sold off.
+    #[serde(default)]
+    pub final_shares: usize,
+    #[serde(default)]
+    pub loans: usize,
+    /// Interest charged on all loans taken.
+    #[serde(default)]
+    pub loan_interest: usize,
 }
 
 impl Stats {
@@ -80,6 +88,11 @@ impl Stats {
             "Minor bonus average".to_string(),
             Stat::Fraction(self.minor_bonus_sum as i32, self.minor_bonuses as i32),
         );
+        s.insert("Loans".to_string(), Stat::Int(self.loans as i32));
+        s.insert(
+            "Loan interest".to_string(),
+            Stat::Int(self.loan_interest as i32),
+        );
         s
     }
 }
@@ -0,0 +1,101 @@
+use crate::errors::AcquireError;
+use crate::options::TeamMajority;
+use crate::Game;
+
+/// Check teams are all the same size and every seat is in exactly one team.
+pub fn validate_teams(teams: &[Vec<usize>], players: usize) -> Result<(), AcquireError> {
+    if teams.is_empty() {
+        return Ok(());
+    }
+    let mut seats: Vec<usize> = teams.iter().flat_map(|t| t.iter().cloned()).collect();
+    seats.sort();
+    if teams.len() < 2
+        || teams.iter().any(|t| t.len() < 2 || t.len() != teams[0].len())
+        || seats != (0..players).collect::<Vec<usize>>()
+    {
+        return Err(AcquireError::InvalidTeams { players });
+    }
+    Ok(())
+}
+
+impl Game {
+    /// The players on the same team as the player, including the player themselves.
+    pub fn team(&self, player: usize) -> Vec<usize> {
+        self.options
+            .teams
+            .iter()
+            .find(|t| t.contains(&player))
+            .cloned()
+            .unwrap_or_else(|| vec![player])
+    }
+
+    pub fn is_teammate(&self, player: usize, other: usize) -> bool {
+        self.team(player).contains(&other)
+    }
+
+    /// The combined cash of the player's team less any debt left unpaid, used for placings.
+    pub fn team_score(&self, player: usize) -> isize {
+        self.team(player)
+            .iter()
+            .map(|p| self.players[*p].money as isize - self.players[*p].debt() as isize)
+            .sum()
+    }
+
+    /// Groups of players whose shares count together for shareholder bonuses.
+    pub(crate) fn bonus_groups(&self) -> Vec<Vec<usize>> {
+        if self.options.teams.is_empty() || self.options.team_majority == TeamMajority::Separate {
+            (0..self.players.len()).map(|p| vec![p]).collect()
+        } else {
+            self.options.teams.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use brdgme_game::Gamer;
+
+    use super::validate_teams;
+    use crate::corp::Corp;
+    use crate::errors::AcquireError;
+    use crate::options::Options;
+    use crate::{Game, STARTING_MONEY};
+
+    #[test]
+    fn validate_teams_works() {
+        assert!(validate_teams(&[], 4).is_ok());
+        assert!(validate_teams(&[vec![0, 2], vec![1, 3]], 4).is_ok());
+        assert!(validate_teams(&[vec![0, 1, 2], vec![3]], 4).is_err());
+        assert_eq!(
+            Err(AcquireError::InvalidTeams { players: 4 }),
+            validate_teams(&[vec![0, 1], vec![1, 2]], 4)
+        );
+    }
+
+    #[test]
+    fn combined_majority_and_team_placings() {
+        let players: Vec<String> = (0..4).map(|p| format!("p{}", p)).collect();
+        let mut g: Game = "FF0
+                           ..A
+                           ..A
+                           1.2
+                           ..3"
+            .into();
+        g.options = Options {
+            teams: vec![vec![0, 2], vec![1, 3]],
+            ..Options::default()
+        };
+        g.players[0].shares.insert(Corp::American, 3);
+        g.players[1].shares.insert(Corp::American, 4);
+        g.players[2].shares.insert(Corp::American, 2);
+        g.command(0, "play a3", &players)
+            .expect("expected 'play a3' to work");
+        g.command(0, "merge am into fe", &players)
+            .expect("expected 'merge am into fe' to work");
+        // Team 0 holds 5 combined against team 1's 4, so it takes the major bonus.
+        let major = g.options.prices.major_bonus(&Corp::American, 2) / 2;
+        assert_eq!(STARTING_MONEY + major, g.players[0].money);
+        assert_eq!(STARTING_MONEY + major, g.players[2].money);
+        assert_eq!(g.placings()[0], g.placings()[2]);
+    }
+}
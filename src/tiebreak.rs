@@ -0,0 +1,122 @@
+use brdgme_game::Log;
+use brdgme_markup::Node as N;
+
+use crate::i18n::Msg;
+use crate::options::Tiebreak;
+use crate::render::comma_list;
+use crate::Game;
+
+impl Tiebreak {
+    pub fn msg(self) -> Msg {
+        match self {
+            Tiebreak::MostShares => Msg::TiebreakMostShares,
+            Tiebreak::MostMajorBonuses => Msg::TiebreakMostMajorBonuses,
+            Tiebreak::TurnOrder => Msg::TiebreakTurnOrder,
+        }
+    }
+}
+
+impl Game {
+    /// Values from the tiebreak chain for a player's team, higher being better.
+    pub(crate) fn tiebreak_values(&self, player: usize) -> Vec<i32> {
+        self.options
+            .tiebreaks
+            .iter()
+            .map(|t| self.tiebreak_value(*t, player))
+            .collect()
+    }
+
+    fn tiebreak_value(&self, tiebreak: Tiebreak, player: usize) -> i32 {
+        let team = self.team(player);
+        match tiebreak {
+            Tiebreak::MostShares => team.iter()
+                .map(|p| self.players[*p].stats.final_shares as i32)
+                .sum(),
+            Tiebreak::MostMajorBonuses => team.iter()
+                .map(|p| self.players[*p].stats.major_bonuses as i32)
+                .sum(),
+            Tiebreak::TurnOrder => {
+                // Negated so earlier players have higher values.
+                let num_players = self.players.len();
+                let earliest = team.iter()
+                    .map(|p| (p + num_players - self.start_player) % num_players)
+                    .min()
+                    .unwrap_or(0);
+                -(earliest as i32)
+            }
+        }
+    }
+
+    /// Logs explaining how players who finished with the same score were placed.
+    pub(crate) fn tiebreak_logs(&self) -> Vec<Log> {
+        let mut teams: Vec<Vec<usize>> = (0..self.players.len())
+            .filter(|p| !self.resigned.contains(p))
+            .map(|p| self.team(p))
+            .collect();
+        teams.sort();
+        teams.dedup();
+        let mut logs: Vec<Log> = vec![];
+        let mut explained: Vec<isize> = vec![];
+        for team in &teams {
+            let money = self.team_score(team[0]);
+            if explained.contains(&money) {
+                continue;
+            }
+            explained.push(money);
+            let tied: Vec<&Vec<usize>> = teams
+                .iter()
+                .filter(|t| self.team_score(t[0]) == money)
+                .collect();
+            if tied.len() < 2 {
+                continue;
+            }
+            // The first tiebreak which separates any of the tied players.
+            let decider = self.options.tiebreaks.iter().find(|t| {
+                let value = self.tiebreak_value(**t, team[0]);
+                tied.iter()
+                    .any(|other| self.tiebreak_value(**t, other[0]) != value)
+            });
+            let players = || {
+                comma_list(
+                    tied.iter()
+                        .flat_map(|t| t.iter().map(|p| N::Player(*p)))
+                        .collect(),
+                )
+            };
+            let amount = || N::Bold(vec![N::text(format!("${}", money))]);
+            logs.extend(self.public_logs(|l| match decider {
+                Some(t) => l.fill(
+                    Msg::TiedBrokenBy,
+                    vec![players(), amount(), N::text(l.template(t.msg()))],
+                ),
+                None => l.fill(Msg::TiedShared, vec![players(), amount()]),
+            }));
+        }
+        logs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use brdgme_game::Gamer;
+
+    use crate::options::Tiebreak;
+    use crate::Game;
+
+    #[test]
+    fn tiebreaks_separate_equal_cash() {
+        let mut g: Game = "0.1".into();
+        g.finished = true;
+        g.players[0].stats.final_shares = 3;
+        g.players[1].stats.final_shares = 5;
+        assert_eq!(vec![1, 1], g.placings());
+        assert_eq!(1, g.tiebreak_logs().len());
+
+        g.options.tiebreaks = vec![Tiebreak::MostMajorBonuses, Tiebreak::MostShares];
+        assert_eq!(vec![2, 1], g.placings());
+
+        g.options.tiebreaks = vec![Tiebreak::TurnOrder];
+        g.start_player = 1;
+        assert_eq!(vec![2, 1], g.placings());
+    }
+}
@@ -0,0 +1,176 @@
+use brdgme_game::Log;
+use brdgme_markup::Node as N;
+
+use crate::command::Command;
+use crate::corp::Corp;
+use crate::errors::AcquireError;
+use crate::i18n::{Locale, Msg};
+use crate::roster::Roster;
+use crate::undo::UndoBarrier;
+use crate::{Game, Phase};
+
+fn timeout_content(locale: Locale, roster: &Roster, player: usize, command: &Command) -> Vec<N> {
+    match *command {
+        Command::Play(loc) => locale.fill(Msg::TimeoutPlay, vec![N::Player(player), loc.render()]),
+        Command::Found(corp) => {
+            locale.fill(Msg::TimeoutFound, vec![N::Player(player), roster.render(&corp)])
+        }
+        Command::Done => locale.fill(Msg::TimeoutDone, vec![N::Player(player)]),
+        Command::Merge(from, into) => locale.fill(
+            Msg::TimeoutMerge,
+            vec![N::Player(player), roster.render(&from), roster.render(&into)],
+        ),
+        Command::Keep => locale.fill(Msg::TimeoutKeep, vec![N::Player(player)]),
+        _ => locale.fill(Msg::TimeoutOther, vec![N::Player(player)]),
+    }
+}
+
+impl Game {
+    /// Start the clock for the current turn if it hasn't been started. Hosts call this with
+    /// the current time after creating the game and after each command, which keeps the engine
+    /// deterministic.
+    pub fn start_turn_clock(&mut self, now: u64) {
+        if self.turn_started_at.is_none() {
+            self.turn_started_at = Some(now);
+        }
+    }
+
+    /// Seconds the current player has left to act, `None` if there is no time limit. The turn
+    /// is treated as starting now if its clock hasn't been started.
+    pub fn turn_time_remaining(&self, now: u64) -> Option<u64> {
+        let limit = self.options.turn_time_limit?;
+        let started = self.turn_started_at.unwrap_or(now);
+        Some((started + limit).saturating_sub(now))
+    }
+
+    pub fn is_timed_out(&self, now: u64) -> bool {
+        !self.finished && self.turn_time_remaining(now) == Some(0)
+    }
+
+    /// The deterministic action taken on behalf of a player who doesn't act.
+    pub(crate) fn default_command(&self, player: usize) -> Option<Command> {
+        match self.phase {
+            Phase::Play(_) => self.players[player]
+                .tiles
+                .iter()
+                .find(|l| self.board.assert_loc_playable(l).is_ok())
+                .map(|l| Command::Play(*l))
+                .or_else(|| {
+                    if self.can_discard(player) {
+                        self.dead_tiles(player).first().map(|l| Command::Discard(*l))
+                    } else {
+                        None
+                    }
+                }),
+            Phase::Found { .. } => {
+                let available = self.board.available_corps();
+                Corp::iter()
+                    .find(|c| available.contains(c))
+                    .map(|c| Command::Found(*c))
+            }
+            Phase::Buy { .. } => Some(Command::Done),
+            Phase::ChooseMerger { at, .. } => {
+                // Merge into the largest corporation, picking in corporation order for ties.
+                let (from, into) = self.board.merge_candidates(&at);
+                let target = Corp::iter().find(|c| into.contains(c))?;
+                Corp::iter()
+                    .find(|c| *c != target && from.contains(c))
+                    .map(|c| Command::Merge(*c, *target))
+            }
+            Phase::SellOrTrade { .. } => Some(Command::Keep),
+            Phase::SealedBid { .. } | Phase::OpenBid { .. } => Some(Command::Pass),
+        }
+    }
+
+    /// If the current player has run out of time, take default actions for them until it is
+    /// another player's turn. Like `command`, this leaves the next turn's clock for the host to
+    /// start.
+    pub fn timeout(&mut self, now: u64) -> Result<Vec<Log>, AcquireError> {
+        if !self.is_timed_out(now) {
+            return Ok(vec![]);
+        }
+        let player = self.phase.whose_turn();
+        let mut logs: Vec<Log> = vec![];
+        while !self.finished && self.phase.whose_turn() == player {
+            let command = self.default_command(player).ok_or_else(|| {
+                AcquireError::Internal("no default action is available".to_string())
+            })?;
+            logs.extend(self.public_logs(|l| {
+                timeout_content(l, &self.options.roster, player, &command)
+            }));
+            logs.extend(self.run_command(player, command)?.0);
+        }
+        self.clear_undo(UndoBarrier::TimedOut);
+        logs.extend(self.run_bots()?);
+        self.turn_started_at = None;
+        Ok(logs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use brdgme_game::Gamer;
+
+    use crate::board::Loc;
+    use crate::{Game, Phase};
+
+    #[test]
+    fn timeout_plays_default_turn() {
+        let mut g: Game = "..0
+                           ...
+                           1.."
+            .into();
+        g.draw_tiles = (5..11).map(|col| Loc { row: 5, col }).collect();
+        g.options.turn_time_limit = Some(60);
+        g.turn_started_at = Some(1000);
+        assert_eq!(Some(30), g.turn_time_remaining(1030));
+        assert!(g.timeout(1030).expect("expected timeout to work").is_empty());
+        let logs = g.timeout(1060).expect("expected timeout to work");
+        assert!(!logs.is_empty());
+        assert_eq!(Phase::Play(1), g.phase);
+        assert_eq!(None, g.turn_started_at);
+        assert_eq!(6, g.players[0].tiles.len());
+    }
+
+    #[test]
+    fn consecutive_timeouts_wait_for_the_host_clock() {
+        let mut g: Game = "..0
+                           ...
+                           1.."
+            .into();
+        g.draw_tiles = (0..12).map(|col| Loc { row: 6, col }).collect();
+        g.options.turn_time_limit = Some(60);
+        g.start_turn_clock(1000);
+        assert!(!g.timeout(1060).expect("expected timeout to work").is_empty());
+        assert_eq!(Phase::Play(1), g.phase);
+        // The next turn hasn't started until the host starts its clock.
+        assert!(g.timeout(1200).expect("expected timeout to work").is_empty());
+        assert_eq!(Phase::Play(1), g.phase);
+        g.start_turn_clock(1200);
+        assert!(g.timeout(1230).expect("expected timeout to work").is_empty());
+        assert!(!g.timeout(1260).expect("expected timeout to work").is_empty());
+        assert_eq!(Phase::Play(0), g.phase);
+        assert_eq!(None, g.turn_started_at);
+    }
+
+    #[test]
+    fn commands_leave_the_clock_to_the_host() {
+        let players = vec!["mick".to_string(), "steve".to_string()];
+        let mut g: Game = "..0
+                           ...
+                           1.."
+            .into();
+        g.draw_tiles = (5..11).map(|col| Loc { row: 5, col }).collect();
+        g.start_turn_clock(1000);
+        g.start_turn_clock(1010);
+        assert_eq!(Some(1000), g.turn_started_at);
+        g.command(0, "play a3", &players)
+            .expect("expected 'play a3' to work");
+        assert_eq!(Some(1000), g.turn_started_at);
+        g.command(0, "done", &players)
+            .expect("expected 'done' to work");
+        assert_eq!(None, g.turn_started_at);
+        g.start_turn_clock(1050);
+        assert_eq!(Some(1050), g.turn_started_at);
+    }
+}
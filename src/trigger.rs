@@ -0,0 +1,192 @@
+use brdgme_game::{Gamer, Log};
+use brdgme_markup::Node as N;
+
+use crate::corp::GAME_END_SIZE;
+use crate::errors::AcquireError;
+use crate::i18n::{Locale, Msg};
+use crate::options::EndTrigger;
+use crate::render::comma_list;
+use crate::report::EndGameReport;
+use crate::roster::Roster;
+use crate::{EndReason, Game, Phase};
+
+/// The dummy player's average roll, so projections don't depend on the dice.
+const EXPECTED_DUMMY_SHARES: usize = 3;
+
+impl EndReason {
+    pub fn render(self, locale: Locale, roster: &Roster) -> N {
+        N::Group(match self {
+            EndReason::LargestCorp(corp) => locale.fill(
+                Msg::EndReasonLargest,
+                vec![
+                    roster.render(&corp),
+                    N::Bold(vec![N::text(format!("{}", GAME_END_SIZE))]),
+                ],
+            ),
+            EndReason::AllSafe => vec![N::text(locale.template(Msg::EndReasonAllSafe))],
+        })
+    }
+}
+
+impl Game {
+    /// Trigger the end of the game when a turn finishes with the end condition met, when using
+    /// the automatic end trigger.
+    pub(crate) fn auto_trigger_end(&mut self) -> Vec<Log> {
+        if self.options.end_trigger != EndTrigger::Automatic || self.last_turn {
+            return vec![];
+        }
+        let reason = match self.pub_state().end_reason() {
+            Some(reason) => reason,
+            None => return vec![],
+        };
+        self.last_turn = true;
+        self.public_logs(|l| {
+            vec![
+                N::Bold(l.fill(Msg::AutoEnded, vec![reason.render(l, &self.options.roster)])),
+            ]
+        })
+    }
+
+    /// The report ending the game now would give. Settlement runs on a copy holding only what
+    /// it needs, leaving out the undo stack and turn history.
+    fn projected_report(&self) -> Result<EndGameReport, AcquireError> {
+        let mut projected = Game {
+            phase: self.phase.clone(),
+            players: self.players.clone(),
+            board: self.board.clone(),
+            shares: self.shares.clone(),
+            options: self.options.clone(),
+            resigned: self.resigned.clone(),
+            start_player: self.start_player,
+            bank: self.bank,
+            market: self.market.clone(),
+            ..Game::default()
+        };
+        projected.settle(|g| if g.players.len() == 2 { EXPECTED_DUMMY_SHARES } else { 0 })?;
+        projected.end_report.ok_or_else(|| {
+            AcquireError::Internal("expected ending the game to create a report".to_string())
+        })
+    }
+
+    /// Tell a player starting their turn that they can end the game and what the result would
+    /// be, when using the advisory end trigger.
+    pub(crate) fn end_advice(&self, player: usize) -> Result<Vec<Log>, AcquireError> {
+        if self.options.end_trigger != EndTrigger::Advisory || self.phase != Phase::Play(player) {
+            return Ok(vec![]);
+        }
+        let reason = match self.pub_state().end_reason() {
+            Some(reason) => reason,
+            None => return Ok(vec![]),
+        };
+        let report = self.projected_report()?;
+        let l = self.locale(player);
+        let projection = if self.money_visible() && self.shares_visible() {
+            let mut order: Vec<usize> = (0..report.players.len()).collect();
+            order.sort_by_key(|p| report.players[*p].placing);
+            l.fill(
+                Msg::ProjectedStandings,
+                vec![comma_list(
+                    order
+                        .iter()
+                        .map(|p| {
+                            N::Group(vec![
+                                N::Player(*p),
+                                N::text(" "),
+                                N::Bold(vec![N::text(format!(
+                                    "${}",
+                                    report.players[*p].final_cash
+                                ))]),
+                            ])
+                        })
+                        .collect(),
+                )],
+            )
+        } else {
+            // Other players' holdings would be revealed by their projected cash.
+            l.fill(
+                Msg::ProjectedCash,
+                vec![N::Bold(vec![N::text(format!(
+                    "${}",
+                    report.players[player].final_cash
+                ))])],
+            )
+        };
+        Ok(vec![
+            Log::private(
+                l.fill(Msg::AdviseEnd, vec![reason.render(l, &self.options.roster)]),
+                vec![player],
+            ),
+            Log::private(projection, vec![player]),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use brdgme_game::Gamer;
+    use brdgme_markup::Node as N;
+
+    use crate::corp::Corp;
+    use crate::i18n::{Locale, Msg};
+    use crate::options::EndTrigger;
+    use crate::{EndReason, Game};
+
+    #[test]
+    fn triggering_the_end_logs_the_reason() {
+        let mut g: Game = "AAAAAAAAAAA0
+                           .....1......"
+            .into();
+        let logs = g.handle_end_command(0)
+            .expect("expected ending the game to work");
+        assert!(g.last_turn);
+        assert_eq!(
+            vec![N::Bold(Locale::En.fill(
+                Msg::TriggeredEnd,
+                vec![
+                    N::Player(0),
+                    EndReason::AllSafe.render(Locale::En, &g.options.roster),
+                ],
+            ))],
+            logs[0].content
+        );
+    }
+
+    #[test]
+    fn automatic_end_trigger_ends_after_turn() {
+        let players = vec!["mick".to_string(), "steve".to_string()];
+        let mut g: Game = "AAAAAAAAAAA0
+                           .....1......"
+            .into();
+        g.options.end_trigger = EndTrigger::Automatic;
+        assert_eq!(Some(EndReason::AllSafe), g.pub_state().end_reason());
+        g.command(0, "play a12", &players)
+            .expect("expected 'play a12' to work");
+        assert!(!g.is_finished());
+        g.command(0, "done", &players)
+            .expect("expected 'done' to work");
+        assert!(g.is_finished());
+    }
+
+    #[test]
+    fn end_advice_leaves_the_game_alone_and_ignores_the_dice() {
+        let mut g: Game = "AAAAAAAAAAA0
+                           .....1......"
+            .into();
+        g.options.end_trigger = EndTrigger::Advisory;
+        g.players[0].shares.insert(Corp::American, 3);
+        let before = g.clone();
+        let advice = |g: &Game| -> Vec<Vec<N>> {
+            g.end_advice(0)
+                .expect("expected advice to work")
+                .into_iter()
+                .map(|l| l.content)
+                .collect()
+        };
+        let first = advice(&g);
+        assert!(!first.is_empty());
+        for _ in 0..10 {
+            assert_eq!(first, advice(&g));
+        }
+        assert_eq!(before, g);
+    }
+}
@@ -0,0 +1,256 @@
+use serde_derive::{Serialize, Deserialize};
+
+use brdgme_game::Log;
+use brdgme_markup::Node as N;
+
+use std::collections::HashMap;
+
+use crate::board::{Board, Loc};
+use crate::command::Command;
+use crate::corp::Corp;
+use crate::errors::AcquireError;
+use crate::history::TurnRecord;
+use crate::i18n::{Locale, Msg};
+use crate::market::Market;
+use crate::{Game, Phase, Player};
+
+/// The state of the game before a player made a decision, so it can be taken back.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "SavedUndoPoint")]
+pub struct UndoPoint {
+    pub player: usize,
+    pub state: TurnState,
+}
+
+/// The parts of the game a decision can change. Settings, the clock and the undo stack itself
+/// aren't included, and the turn history is only recorded by its length as turns are only
+/// ever added to it.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct TurnState {
+    pub phase: Phase,
+    pub players: Vec<Player>,
+    pub board: Board,
+    pub draw_tiles: Vec<Loc>,
+    pub shares: HashMap<Corp, usize>,
+    pub last_turn: bool,
+    pub turn_discards: usize,
+    pub bank: Option<usize>,
+    pub market: Option<Market>,
+    pub current_turn: TurnRecord,
+    pub history_len: usize,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SavedUndoPoint {
+    Point { player: usize, state: TurnState },
+    /// Undo points used to hold a copy of the whole game.
+    Game { player: usize, game: Box<Game> },
+}
+
+impl From<SavedUndoPoint> for UndoPoint {
+    fn from(saved: SavedUndoPoint) -> Self {
+        match saved {
+            SavedUndoPoint::Point { player, state } => UndoPoint { player, state },
+            SavedUndoPoint::Game { player, game } => UndoPoint {
+                player,
+                state: game.turn_state(),
+            },
+        }
+    }
+}
+
+/// Why earlier decisions can no longer be undone.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum UndoBarrier {
+    /// Tiles were drawn, revealing hidden information.
+    TilesDrawn,
+    /// The dummy player rolled for shares in a 2 player game.
+    DiceRolled,
+    /// Another player has made a decision since.
+    OtherPlayer(usize),
+    /// The player ran out of time and default actions were taken.
+    TimedOut,
+    /// The action can't be taken back, such as triggering the end of the game or resigning.
+    Irreversible,
+}
+
+impl UndoBarrier {
+    /// The catalogue entry explaining why undoing is blocked.
+    pub fn msg(&self) -> Msg {
+        match *self {
+            UndoBarrier::TilesDrawn => Msg::ErrUndoTilesDrawn,
+            UndoBarrier::DiceRolled => Msg::ErrUndoDiceRolled,
+            UndoBarrier::OtherPlayer(_) => Msg::ErrUndoOtherDecision,
+            UndoBarrier::TimedOut => Msg::ErrUndoTimedOut,
+            UndoBarrier::Irreversible => Msg::ErrUndoIrreversible,
+        }
+    }
+}
+
+fn total_merges(players: &[Player]) -> usize {
+    players.iter().map(|p| p.stats.merges).sum()
+}
+
+impl Game {
+    /// A copy of the state a decision can change, to be restored when undoing.
+    fn turn_state(&self) -> TurnState {
+        TurnState {
+            phase: self.phase.clone(),
+            players: self.players.clone(),
+            board: self.board.clone(),
+            draw_tiles: self.draw_tiles.clone(),
+            shares: self.shares.clone(),
+            last_turn: self.last_turn,
+            turn_discards: self.turn_discards,
+            bank: self.bank,
+            market: self.market.clone(),
+            current_turn: self.current_turn.clone(),
+            history_len: self.turn_history.len(),
+        }
+    }
+
+    /// Put back the state from before a decision. Players keep the locales they have chosen
+    /// since, as changing language isn't a decision which can be undone.
+    fn restore(&mut self, state: TurnState) {
+        let locales: Vec<Locale> = self.players.iter().map(|p| p.locale).collect();
+        self.phase = state.phase;
+        self.players = state.players;
+        self.board = state.board;
+        self.draw_tiles = state.draw_tiles;
+        self.shares = state.shares;
+        self.last_turn = state.last_turn;
+        self.turn_discards = state.turn_discards;
+        self.bank = state.bank;
+        self.market = state.market;
+        self.current_turn = state.current_turn;
+        self.turn_history.truncate(state.history_len);
+        for (player, locale) in self.players.iter_mut().zip(locales) {
+            player.locale = locale;
+        }
+    }
+
+    pub(crate) fn clear_undo(&mut self, barrier: UndoBarrier) {
+        self.undo_stack.clear();
+        self.undo_barrier = Some(barrier);
+    }
+
+    /// Run a command, recording an undo point if the command can be taken back.
+    pub(crate) fn run_undoable_command(
+        &mut self,
+        player: usize,
+        command: Command,
+    ) -> Result<(Vec<Log>, bool), AcquireError> {
+        let snapshot = self.turn_state();
+        let (logs, can_undo) = self.run_command(player, command)?;
+        if self.undo_stack.iter().any(|p| p.player != player) {
+            self.clear_undo(UndoBarrier::OtherPlayer(player));
+        }
+        if can_undo && !self.finished {
+            self.undo_stack.push(UndoPoint {
+                player,
+                state: snapshot,
+            });
+            self.undo_barrier = None;
+        } else if self.draw_tiles.len() != snapshot.draw_tiles.len() {
+            self.clear_undo(UndoBarrier::TilesDrawn);
+        } else if self.players.len() == 2
+            && total_merges(&self.players) != total_merges(&snapshot.players)
+        {
+            self.clear_undo(UndoBarrier::DiceRolled);
+        } else {
+            self.clear_undo(UndoBarrier::Irreversible);
+        }
+        Ok((logs, can_undo))
+    }
+
+    pub fn can_undo(&self, player: usize) -> bool {
+        !self.finished
+            && self.undo_stack
+                .last()
+                .map(|p| p.player == player)
+                .unwrap_or(false)
+    }
+
+    /// Take back the player's last decision.
+    pub fn undo(&mut self, player: usize) -> Result<Vec<Log>, AcquireError> {
+        self.ensure_not_finished()?;
+        match self.undo_stack.last() {
+            Some(p) if p.player == player => {}
+            Some(_) => return Err(AcquireError::UndoOtherPlayer),
+            None => {
+                return Err(self.undo_barrier
+                    .map(AcquireError::UndoBlocked)
+                    .unwrap_or(AcquireError::NothingToUndo))
+            }
+        }
+        let point = self.undo_stack.pop().expect("expected an undo point");
+        self.restore(point.state);
+        Ok(self.public_logs(|l| l.fill(Msg::Undid, vec![N::Player(player)])))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use brdgme_game::Gamer;
+
+    use super::UndoBarrier;
+    use crate::board::Loc;
+    use crate::corp::Corp;
+    use crate::errors::AcquireError;
+    use crate::i18n::Locale;
+    use crate::{Game, Phase, STARTING_MONEY};
+
+    #[test]
+    fn undo_buy_and_play_works() {
+        let players = vec!["mick".to_string(), "steve".to_string()];
+        let mut g: Game = "AA0
+                           ...
+                           1.."
+            .into();
+        g.draw_tiles = (0..6).map(|col| Loc { row: 5, col }).collect();
+        g.command(0, "play a3", &players)
+            .expect("expected 'play a3' to work");
+        g.command(0, "buy 2 am", &players)
+            .expect("expected 'buy 2 am' to work");
+        assert!(g.players[0].money < STARTING_MONEY);
+        assert!(g.undo(1).is_err());
+        g.command(0, "undo", &players)
+            .expect("expected undoing the buy to work");
+        assert_eq!(STARTING_MONEY, g.players[0].money);
+        assert_eq!(Some(&0), g.players[0].shares.get(&Corp::American));
+        assert_eq!(
+            Phase::Buy {
+                player: 0,
+                remaining: 3,
+            },
+            g.phase
+        );
+        g.undo(0).expect("expected undoing the play to work");
+        assert_eq!(Phase::Play(0), g.phase);
+        assert_eq!(vec![Loc { row: 0, col: 2 }], g.players[0].tiles);
+        g.command(0, "play a3", &players)
+            .expect("expected 'play a3' to work");
+        g.command(0, "done", &players)
+            .expect("expected 'done' to work");
+        assert_eq!(
+            Some(AcquireError::UndoBlocked(UndoBarrier::TilesDrawn)),
+            g.undo(0).err()
+        );
+    }
+
+    #[test]
+    fn undo_keeps_language_changes() {
+        let players = vec!["mick".to_string(), "steve".to_string()];
+        let mut g: Game = "AA0
+                           ..1"
+            .into();
+        g.command(0, "play a3", &players)
+            .expect("expected 'play a3' to work");
+        g.command(1, "language de", &players)
+            .expect("expected changing language to work");
+        g.undo(0).expect("expected undoing the play to work");
+        assert_eq!(Phase::Play(0), g.phase);
+        assert_eq!(Locale::De, g.players[1].locale);
+    }
+}
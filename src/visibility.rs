@@ -0,0 +1,177 @@
+use brdgme_game::Log;
+use brdgme_markup::Node as N;
+
+use crate::auction::Bid;
+use crate::board::Loc;
+use crate::i18n::{Locale, Msg};
+use crate::render::comma_list;
+use crate::{Game, Phase, PubState};
+
+/// Shown in place of a value the viewer isn't allowed to see.
+pub fn redacted() -> N {
+    N::text("?")
+}
+
+impl Game {
+    pub fn tiles_visible(&self) -> bool {
+        self.finished || self.options.visibility.tiles
+    }
+
+    pub fn money_visible(&self) -> bool {
+        self.finished || self.options.visibility.money
+    }
+
+    pub fn shares_visible(&self) -> bool {
+        self.finished || self.options.visibility.shares
+    }
+
+    /// The public state as seen by a player, or by a spectator if `viewer` is `None`. Holdings
+    /// hidden by the visibility options are redacted for everyone but their owner and partners.
+    pub fn pub_state_for(&self, viewer: Option<usize>) -> PubState {
+        let mut state: PubState = self.to_owned().into();
+        let shown_to_viewer = viewer.map(|v| self.team(v)).unwrap_or_else(|| vec![]);
+        for (p, pub_player) in state.players.iter_mut().enumerate() {
+            if shown_to_viewer.contains(&p) {
+                continue;
+            }
+            if !self.tiles_visible() {
+                pub_player.tiles = None;
+            }
+            if !self.money_visible() {
+                pub_player.money = None;
+            }
+            if !self.shares_visible() {
+                pub_player.shares = None;
+            }
+        }
+        if let Phase::SealedBid { ref mut bids, .. } = state.phase {
+            for (p, bid) in bids.iter_mut().enumerate() {
+                if *bid != Bid::Pending && !shown_to_viewer.contains(&p) {
+                    *bid = Bid::Hidden;
+                }
+            }
+        }
+        if !self.money_visible() {
+            // The bank's balance would give away what the other players hold between them.
+            state.bank = None;
+        }
+        state
+    }
+
+    /// Logs containing values which are only shown to some players and their partners when
+    /// `hidden` is set. Everyone else, including the public record, gets the redacted text. The
+    /// content function is passed whether the values can be shown.
+    pub(crate) fn redacted_logs<F>(&self, shown_to: &[usize], hidden: bool, content: F) -> Vec<Log>
+    where
+        F: Fn(Locale, bool) -> Vec<N>,
+    {
+        if !hidden {
+            return self.public_logs(|l| content(l, true));
+        }
+        let mut shown_to: Vec<usize> = shown_to.iter().flat_map(|p| self.team(*p)).collect();
+        shown_to.sort();
+        shown_to.dedup();
+        let mut logs = self.public_logs_except(&shown_to, |l| content(l, false));
+        logs.extend(self.private_logs(&shown_to, |l| content(l, true)));
+        logs
+    }
+
+    /// Logs for tiles a player drew, which are public when playing with open tiles.
+    pub(crate) fn drawn_tiles_logs(&self, player: usize, tiles: &[Loc]) -> Vec<Log> {
+        let tile_list = || comma_list(tiles.iter().map(|t| t.render()).collect());
+        if self.tiles_visible() {
+            self.public_logs(|l| l.fill(Msg::PlayerDrew, vec![N::Player(player), tile_list()]))
+        } else {
+            let mut logs = vec![Log::private(
+                self.locale(player).fill(Msg::YouDrew, vec![tile_list()]),
+                vec![player],
+            )];
+            let partners: Vec<usize> = self.team(player)
+                .into_iter()
+                .filter(|p| *p != player)
+                .collect();
+            logs.extend(self.private_logs(&partners, |l| {
+                l.fill(Msg::PlayerDrew, vec![N::Player(player), tile_list()])
+            }));
+            logs
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use brdgme_game::{Gamer, Log};
+    use brdgme_markup::Node as N;
+
+    use super::redacted;
+    use crate::board::Loc;
+    use crate::corp::Corp;
+    use crate::{Game, Phase};
+
+    /// Whether every log the player can see redacts a share count rather than showing it.
+    /// Players see logs addressed to them and spectators see public logs.
+    fn count_hidden_from(logs: &[Log], player: Option<usize>, count: usize) -> bool {
+        let seen: Vec<&Log> = logs
+            .iter()
+            .filter(|l| match player {
+                Some(p) => l.to.contains(&p),
+                None => l.public,
+            })
+            .collect();
+        !seen.is_empty() && seen.iter().all(|l| {
+            l.content.contains(&redacted())
+                && !l.content
+                    .contains(&N::Bold(vec![N::text(format!("{}", count))]))
+        })
+    }
+
+    #[test]
+    fn hidden_holdings_are_redacted_for_others() {
+        let mut g: Game = "0.1".into();
+        g.options.visibility.money = false;
+        g.options.visibility.tiles = true;
+        let state = g.player_state(0);
+        assert_eq!(Some(g.players[0].money), state.public.players[0].money);
+        assert_eq!(None, state.public.players[1].money);
+        assert_eq!(
+            Some(vec![Loc { row: 0, col: 2 }]),
+            state.public.players[1].tiles
+        );
+        assert!(state.public.players[1].shares.is_some());
+        assert_eq!(None, g.pub_state().players[0].money);
+        g.finished = true;
+        assert!(g.pub_state().players[0].money.is_some());
+    }
+
+    #[test]
+    fn hidden_share_counts_are_redacted_in_logs() {
+        let players = vec!["mick".to_string(), "steve".to_string()];
+        let mut g: Game = "AA0
+                           ..1"
+            .into();
+        g.options.visibility.shares = false;
+        g.command(0, "play a3", &players)
+            .expect("expected 'play a3' to work");
+        let (logs, _) = g.handle_buy_command(0, 3, Corp::American)
+            .expect("expected buying to work");
+        assert!(count_hidden_from(&logs, Some(1), 3));
+        assert!(count_hidden_from(&logs, None, 3));
+        assert!(!count_hidden_from(&logs, Some(0), 3));
+        let logs = g.sell(0, 1, &Corp::American)
+            .expect("expected selling to work");
+        assert!(count_hidden_from(&logs, Some(1), 1));
+        assert!(count_hidden_from(&logs, None, 1));
+        g.players[0].shares.insert(Corp::American, 4);
+        g.phase = Phase::SellOrTrade {
+            player: 0,
+            corp: Corp::American,
+            into: Corp::Tower,
+            at: Loc { row: 0, col: 0 },
+            turn_player: 0,
+        };
+        let (logs, _) = g.handle_trade_command(0, 2)
+            .expect("expected trading to work");
+        assert!(count_hidden_from(&logs, Some(1), 2));
+        assert!(count_hidden_from(&logs, None, 2));
+    }
+}
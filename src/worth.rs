@@ -0,0 +1,66 @@
+use crate::corp::Corp;
+use crate::PubState;
+
+impl PubState {
+    /// The value of a player's shares at current prices, if their shares are visible. Shares in
+    /// corporations which aren't on the board are worthless.
+    pub fn share_value(&self, player: usize) -> Option<usize> {
+        let shares = self.players.get(player)?.shares.as_ref()?;
+        Some(
+            Corp::iter()
+                .map(|c| {
+                    let size = self.board.corp_size(c);
+                    if size == 0 {
+                        0
+                    } else {
+                        shares.get(c).cloned().unwrap_or(0) * self.share_price(c)
+                    }
+                })
+                .sum(),
+        )
+    }
+
+    /// Cash plus share value less debt, if both are visible.
+    pub fn net_worth(&self, player: usize) -> Option<usize> {
+        let pub_player = self.players.get(player)?;
+        let money = pub_player.money?;
+        self.share_value(player).map(|v| (v + money).saturating_sub(pub_player.debt))
+    }
+
+    /// Share value less debt, a lower bound on net worth when cash is hidden.
+    pub fn min_worth(&self, player: usize) -> Option<usize> {
+        let debt = self.players.get(player)?.debt;
+        self.share_value(player).map(|v| v.saturating_sub(debt))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use brdgme_game::Gamer;
+
+    use crate::corp::Corp;
+    use crate::loans::Loan;
+    use crate::options::Options;
+    use crate::{Game, STARTING_MONEY};
+
+    #[test]
+    fn net_worth_uses_public_information() {
+        let mut g: Game = "AA0
+                           ..1"
+            .into();
+        g.options = Options::hidden_cash();
+        g.players[1].shares.insert(Corp::American, 2);
+        let value = 2 * g.options.prices.value(&Corp::American, 2);
+        let state = g.player_state(0).public;
+        assert_eq!(Some(value), state.share_value(1));
+        assert_eq!(None, state.net_worth(1));
+        assert_eq!(Some(STARTING_MONEY), state.net_worth(0));
+        assert_eq!(Some(value), state.min_worth(1));
+        g.players[1].loans.push(Loan {
+            owed: 100,
+            turns_left: None,
+        });
+        let state = g.player_state(0).public;
+        assert_eq!(Some(value - 100), state.min_worth(1));
+    }
+}